@@ -0,0 +1,268 @@
+//! Catalogs of known galaxies, mirroring the stage list of the game.
+//!
+//! Unlike Super Mario Galaxy, each galaxy state stores its own collection of
+//! mission states, so the number of missions is read from the save file. The
+//! kind of each mission, such as a Prankster Comet, hidden Star or Green Star,
+//! is not catalogued, as the scenario data of the game is undocumented.
+
+use std::borrow::Cow;
+
+use galaxy_save_core::hash::{HashCode, HashCode16, HashKey};
+
+#[cfg(all(test, feature = "serde"))]
+mod tests;
+
+/// Defines the enumeration of known galaxies alongside their names and worlds.
+macro_rules! galaxies {
+    (
+        $(#[$meta:meta])*
+        $vis:vis enum $name:ident {
+            $(
+                $variant:ident = ($display:literal, $world:ident),
+            )*
+        }
+    ) => {
+        $(#[$meta])*
+        #[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+        $vis enum $name {
+            $(
+                #[doc = concat!("The ", $display, ".")]
+                $variant,
+            )*
+        }
+
+        impl $name {
+            /// Every known galaxy, in the order of the World Map.
+            pub const ALL: &[Self] = &[$(Self::$variant,)*];
+
+            /// Returns the internal name of the galaxy.
+            pub const fn label(self) -> &'static str {
+                match self {
+                    $(Self::$variant => stringify!($variant),)*
+                }
+            }
+
+            /// Returns the English name of the galaxy.
+            pub const fn name(self) -> &'static str {
+                match self {
+                    $(Self::$variant => $display,)*
+                }
+            }
+
+            /// Returns the world of the galaxy.
+            pub const fn world(self) -> World {
+                match self {
+                    $(Self::$variant => World::$world,)*
+                }
+            }
+        }
+    };
+}
+
+galaxies! {
+    /// A known galaxy.
+    pub enum Galaxy {
+        IslandFleetGalaxy = ("Sky Station Galaxy", One),
+        YosshiHomeGalaxy = ("Yoshi Star Galaxy", One),
+        DigMineGalaxy = ("Spin-Dig Galaxy", One),
+        MokumokuValleyGalaxy = ("Fluffy Bluff Galaxy", One),
+        RedBlueExGalaxy = ("Flip-Swap Galaxy", One),
+        AbekobeGalaxy = ("Rightside Down Galaxy", One),
+        VsKoopaJrLv1Galaxy = ("Bowser Jr.'s Fiery Flotilla", One),
+        HomeCenterGalaxy = ("Puzzle Plank Galaxy", Two),
+        BigWaterFallGalaxy = ("Hightail Falls Galaxy", Two),
+        GoroRockGalaxy = ("Boulder Bowl Galaxy", Two),
+        StarCreekGalaxy = ("Cosmic Cove Galaxy", Two),
+        JungleGliderGalaxy = ("Wild Glide Galaxy", Two),
+        FlowerHighlandGalaxy = ("Honeybloom Galaxy", Two),
+        VsKoopaLv1Galaxy = ("Bowser's Lava Lair", Two),
+        BigTree2Galaxy = ("Tall Trunk Galaxy", Three),
+        CloudGardenGalaxy = ("Cloudy Court Galaxy", Three),
+        TeresaLabyrinthGalaxy = ("Haunty Halls Galaxy", Three),
+        WhiteSnowGalaxy = ("Freezy Flake Galaxy", Three),
+        TamakoroPlanetGalaxy = ("Rolling Masterpiece Galaxy", Three),
+        TimerSwitchingPlatformGalaxy = ("Beat Block Galaxy", Three),
+        KoopaJrLv2Galaxy = ("Bowser Jr.'s Fearsome Fleet", Three),
+        BigGalaxy = ("Supermassive Galaxy", Four),
+        MagicGalaxy = ("Flipsy Galaxy", Four),
+        TropicalResortGalaxy = ("Starshine Beach Galaxy", Four),
+        WanwanFactoryGalaxy = ("Chompworks Galaxy", Four),
+        MysteryCandyGalaxy = ("Sweet Mystery Galaxy", Four),
+        HoneyBeeVillageGalaxy = ("Honeyhop Galaxy", Four),
+        VsKoopaGravityGalaxy = ("Bowser's Gravity Gauntlet", Four),
+        ThunderFleetGalaxy = ("Space Storm Galaxy", Five),
+        QuicksandGalaxy = ("Slipsand Galaxy", Five),
+        KachikochiLavaGalaxy = ("Shiverburn Galaxy", Five),
+        GhostConveyorGalaxy = ("Boo Moon Galaxy", Five),
+        OmoteuLandGalaxy = ("Upside Dizzy Galaxy", Five),
+        ChallengeGliderGalaxy = ("Fleet Glide Galaxy", Five),
+        VsKoopaJrLv3Galaxy = ("Bowser Jr.'s Boom Bunker", Five),
+        TwisterTowerGalaxy = ("Melty Monster Galaxy", Six),
+        AncientExcavationGalaxy = ("Clockwork Ruins Galaxy", Six),
+        DimensionBigCastleGalaxy = ("Throwback Galaxy", Six),
+        LongForCastleGalaxy = ("Battle Belt Galaxy", Six),
+        MemoryRoadGalaxy = ("Flash Black Galaxy", Six),
+        UnderGroundDangeonGalaxy = ("Slimy Spring Galaxy", Six),
+        VsKoopaLv3Galaxy = ("Bowser's Galaxy Generator", Six),
+        MarioOnMarioGalaxy = ("Mario Squared Galaxy", Special),
+        TamakoroSliderGalaxy = ("Rolling Coaster Galaxy", Special),
+        SecretAthleticGalaxy = ("Twisty Trials Galaxy", Special),
+        MadnessOnimasuGalaxy = ("Stone Cyclone Galaxy", Special),
+        KingOfKingsGalaxy = ("Boss Blitz Galaxy", Special),
+        SwitchMoveBlockGalaxy = ("Flip-Out Galaxy", Special),
+        ChaosGravityGalaxy = ("Grandmaster Galaxy", Special),
+    }
+}
+
+impl Galaxy {
+    /// Returns the galaxy corresponding to the hashed internal name, if known.
+    pub fn from_hash_code(hash: impl Into<HashCode16>) -> Option<Self> {
+        let hash = hash.into();
+
+        Self::ALL.iter().copied().find(|g| hash == g.hash_code())
+    }
+
+    /// Returns the hash of the internal name.
+    pub fn hash_code(self) -> HashCode {
+        HashCode::from(self.label())
+    }
+}
+
+impl From<Galaxy> for HashCode {
+    fn from(galaxy: Galaxy) -> Self {
+        galaxy.hash_code()
+    }
+}
+
+impl From<Galaxy> for HashKey<'_> {
+    fn from(galaxy: Galaxy) -> Self {
+        Self::Label(Cow::Borrowed(galaxy.label()))
+    }
+}
+
+/// A world of the World Map.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub enum World {
+    /// World 1.
+    One,
+
+    /// World 2.
+    Two,
+
+    /// World 3.
+    Three,
+
+    /// World 4.
+    Four,
+
+    /// World 5.
+    Five,
+
+    /// World 6.
+    Six,
+
+    /// World S.
+    Special,
+}
+
+impl World {
+    /// Every world, in the order of the World Map.
+    pub const ALL: &[Self] = &[
+        Self::One,
+        Self::Two,
+        Self::Three,
+        Self::Four,
+        Self::Five,
+        Self::Six,
+        Self::Special,
+    ];
+
+    /// Returns the positive number of the world.
+    pub const fn world_no(self) -> usize {
+        self as usize + 1
+    }
+
+    /// Returns the English name of the world.
+    pub const fn name(self) -> &'static str {
+        match self {
+            Self::One => "World 1",
+            Self::Two => "World 2",
+            Self::Three => "World 3",
+            Self::Four => "World 4",
+            Self::Five => "World 5",
+            Self::Six => "World 6",
+            Self::Special => "World S",
+        }
+    }
+
+    /// Returns an iterator over the galaxies of the world.
+    pub fn galaxies(self) -> impl Iterator<Item = Galaxy> {
+        Galaxy::ALL
+            .iter()
+            .copied()
+            .filter(move |g| g.world() == self)
+    }
+}
+
+/// Serializes hashed galaxy names to their internal name, falling back to the
+/// catalog if the name is not found in the current label map.
+///
+/// If translations are enabled and the label map knows one for the name, the
+/// name is serialized alongside its translation.
+#[cfg(feature = "serde")]
+pub(crate) mod serde_galaxy_name {
+    use galaxy_save_core::hash::{HashCode, HashCode16, HashCodeMap};
+    use serde::{Deserialize, Deserializer, Serialize, Serializer};
+
+    use super::Galaxy;
+
+    /// The serialized form of a galaxy name accompanied by its translation.
+    #[derive(Serialize, Deserialize)]
+    #[serde(untagged)]
+    enum GalaxyName {
+        /// The internal name, or hexadecimal if unknown.
+        Label(String),
+
+        /// The internal name alongside its translation.
+        Translated { label: String, translation: String },
+    }
+
+    pub fn serialize<S>(hash: &HashCode16, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: Serializer,
+    {
+        let (label, translation) = {
+            let map = HashCodeMap::current();
+            let map = map.lock();
+            let hash = HashCode::from(*hash);
+
+            (
+                map.label_of(hash, Some(u16::BITS)).cloned(),
+                map.translation_of(hash, Some(u16::BITS)).cloned(),
+            )
+        };
+        let label = label
+            .or_else(|| Galaxy::from_hash_code(*hash).map(|g| g.label().into()))
+            .unwrap_or_else(|| hash.to_label());
+
+        match translation {
+            Some(translation) => GalaxyName::Translated { label, translation },
+            None => GalaxyName::Label(label),
+        }
+        .serialize(serializer)
+    }
+
+    pub fn deserialize<'de, D>(deserializer: D) -> Result<HashCode16, D::Error>
+    where
+        D: Deserializer<'de>,
+    {
+        let label = match GalaxyName::deserialize(deserializer)? {
+            GalaxyName::Label(label) | GalaxyName::Translated { label, .. } => label,
+        };
+
+        match Galaxy::ALL.iter().find(|g| g.label() == label) {
+            Some(galaxy) => Ok(galaxy.hash_code().into()),
+            None => HashCode16::from_label(&label).map_err(serde::de::Error::custom),
+        }
+    }
+}
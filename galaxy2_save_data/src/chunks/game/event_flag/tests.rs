@@ -0,0 +1,177 @@
+use binrw::{BinRead, BinWrite, io::Cursor};
+use galaxy_save_core::{
+    bin::{BinaryDataChunkHolder, BufferOverflowError, CapacityError, ChunkHolder},
+    event::InsertEventError,
+    hash::Encoding,
+    save::Platform,
+};
+
+use super::*;
+use crate::chunks::game::{GameDataChunk, GameDataHolder};
+
+/// Returns a container holding an event flag state without key-value pairs.
+fn holder() -> BinaryDataChunkHolder<GameDataChunk> {
+    let event_flag = SaveDataStorageEventFlag {
+        event_flag: Vec::new(),
+    };
+
+    BinaryDataChunkHolder {
+        chunks: vec![GameDataChunk::EventFlag(BinaryDataChunk {
+            inner: event_flag,
+        })],
+    }
+}
+
+/// Fills the data buffer with placeholder keys, leaving an odd number of keys
+/// so that exactly one more key fits within the alignment padding.
+fn fill(holder: &mut BinaryDataChunkHolder<GameDataChunk>) {
+    let mut raw = 0u32;
+
+    while holder.remaining_capacity().unwrap() > 0 || raw.is_multiple_of(2) {
+        holder
+            .event_flag_mut()
+            .unwrap()
+            .push(HashCode::from_raw(raw), false);
+        raw += 1;
+    }
+}
+
+#[test]
+fn test_insert() {
+    let mut holder = holder();
+
+    assert_eq!(
+        holder
+            .insert_event_flag("FlagA", true, Platform::Wii)
+            .unwrap(),
+        None
+    );
+    assert_eq!(
+        holder
+            .insert_event_flag("FlagA", false, Platform::Wii)
+            .unwrap(),
+        Some(true)
+    );
+    assert_eq!(holder.event_flag().unwrap().get("FlagA"), Some(false));
+    assert_eq!(holder.event_flag().unwrap().iter().count(), 1);
+}
+
+#[test]
+fn test_insert_hashes_under_platform_encoding() {
+    let label = "ハチマリオ初変身";
+    let mut wii = holder();
+    let mut switch = holder();
+
+    wii.insert_event_flag(label, true, Platform::Wii).unwrap();
+    switch
+        .insert_event_flag(label, true, Platform::Switch)
+        .unwrap();
+
+    let shift_jis = Encoding::ShiftJis.hash(label).unwrap();
+    let utf8 = Encoding::Utf8.hash(label).unwrap();
+
+    assert_eq!(wii.event_flag().unwrap().get(shift_jis), Some(true));
+    assert_eq!(wii.event_flag().unwrap().get(utf8), None);
+    assert_eq!(switch.event_flag().unwrap().get(utf8), Some(true));
+    assert_eq!(switch.event_flag().unwrap().get(shift_jis), None);
+}
+
+#[test]
+fn test_insert_within_alignment() {
+    let mut holder = holder();
+    fill(&mut holder);
+
+    let len = holder.event_flag().unwrap().iter().count();
+
+    assert_eq!(
+        holder
+            .insert_event_flag("FlagA", true, Platform::Wii)
+            .unwrap(),
+        None
+    );
+    assert!(matches!(
+        holder.insert_event_flag("FlagB", true, Platform::Wii),
+        Err(InsertEventError::Capacity(CapacityError::BufferOverflow(BufferOverflowError { size, capacity })))
+            if size == capacity + 4 && capacity == GameDataChunk::CAPACITY as u64
+    ));
+    assert!(!holder.event_flag().unwrap().contains("FlagB"));
+    assert_eq!(holder.event_flag().unwrap().iter().count(), len + 1);
+}
+
+#[test]
+fn test_insert_existing_key_at_capacity() {
+    let mut holder = holder();
+    fill(&mut holder);
+    holder
+        .insert_event_flag("FlagA", true, Platform::Wii)
+        .unwrap();
+
+    assert_eq!(
+        holder
+            .insert_event_flag("FlagA", false, Platform::Wii)
+            .unwrap(),
+        Some(true)
+    );
+    assert_eq!(holder.event_flag().unwrap().get("FlagA"), Some(false));
+}
+
+#[test]
+fn test_insert_missing_chunk() {
+    let mut holder = holder();
+    holder.chunks.clear();
+
+    assert!(matches!(
+        holder.insert_event_flag("FlagA", true, Platform::Wii),
+        Err(InsertEventError::MissingChunk)
+    ));
+}
+
+#[test]
+fn test_remove() {
+    let mut holder = holder();
+    holder
+        .insert_event_flag("FlagA", true, Platform::Wii)
+        .unwrap();
+
+    let event_flag = holder.event_flag_mut().unwrap();
+
+    assert_eq!(event_flag.remove("FlagA"), Some(true));
+    assert_eq!(event_flag.remove("FlagA"), None);
+    assert!(!event_flag.contains("FlagA"));
+}
+
+#[test]
+fn test_get_label_under_any_encoding() {
+    let label = "ハチマリオ初変身";
+
+    for platform in [Platform::Wii, Platform::Switch] {
+        let mut holder = holder();
+        let hash = HashCode::for_label(label, platform).unwrap();
+        holder.event_flag_mut().unwrap().push(hash, true);
+
+        let event_flag = holder.event_flag().unwrap();
+
+        assert_eq!(event_flag.get(label), Some(true), "{platform:?}");
+        assert!(event_flag.contains(label), "{platform:?}");
+    }
+}
+
+#[test]
+fn test_read_get_flag_under_any_encoding() {
+    let flag = Flag::BeeMarioFirstTransform;
+
+    for platform in [Platform::Wii, Platform::Switch] {
+        let hash = HashCode::for_label(flag.label(), platform).unwrap();
+        let mut writer = Cursor::new(Vec::new());
+        GameEventFlag::new(hash, true)
+            .write_be(&mut writer)
+            .unwrap();
+
+        let mut reader = Cursor::new(writer.into_inner());
+        let event_flag =
+            SaveDataStorageEventFlag::read_be_args(&mut reader, (size_of::<GameEventFlag>(),))
+                .unwrap();
+
+        assert_eq!(event_flag.get_flag(flag), Some(true), "{platform:?}");
+    }
+}
@@ -1,7 +1,12 @@
 //! Types associated with galaxy state.
 
+use std::{
+    io::{Read, Seek, SeekFrom, Write},
+    slice,
+};
+
 use bilge::prelude::*;
-use binrw::binrw;
+use binrw::{BinRead, BinResult, BinWrite, Endian, binrw};
 use galaxy_save_core::{
     bin::{BinaryDataContentHeaderSerializer, Chunk, HeaderSerializer},
     hash::{HashCode, HashCode16},
@@ -10,11 +15,16 @@ use galaxy_save_core::{
 #[cfg(feature = "serde")]
 use serde::{Deserialize, Serialize};
 
+use crate::catalog::Galaxy;
+
+#[cfg(test)]
+mod tests;
+
 /// A container for galaxy state.
 #[binrw]
 #[br(import(_data_size: usize))]
 #[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
-#[derive(Debug)]
+#[derive(Debug, Default)]
 pub struct SaveDataStorageGalaxy {
     /// The number of stored galaxy states.
     #[br(temp)]
@@ -25,16 +35,35 @@ pub struct SaveDataStorageGalaxy {
     #[bw(calc = SaveDataStorageGalaxyStage::header_serializer())]
     _stage_serializer: BinaryDataContentHeaderSerializer<SaveDataStorageGalaxyStage>,
 
+    /// The dynamic reader/writer for the mission states, as written by
+    /// [`dyn_scenario_serializer`](Self::dyn_scenario_serializer).
     #[br(temp)]
-    #[bw(calc = SaveDataStorageGalaxyScenario::header_serializer())]
+    #[bw(calc = self.dyn_scenario_serializer())]
     _scenario_serializer: BinaryDataContentHeaderSerializer<SaveDataStorageGalaxyScenario>,
 
     /// The collection of galaxy states.
-    #[br(count = galaxy_num as usize)]
+    #[br(count = galaxy_num as usize, args { inner: (&_scenario_serializer,) })]
+    #[bw(args(&_scenario_serializer))]
     galaxy: Vec<SaveDataStorageGalaxyStage>,
+
+    /// The dynamic reader/writer for the mission states read from the file, if any.
+    #[br(calc = Some(_scenario_serializer))]
+    #[bw(ignore)]
+    #[cfg_attr(feature = "serde", serde(skip))]
+    scenario_serializer: Option<BinaryDataContentHeaderSerializer<SaveDataStorageGalaxyScenario>>,
 }
 
 impl SaveDataStorageGalaxy {
+    /// Returns an iterator over the galaxy states.
+    pub fn iter(&self) -> slice::Iter<'_, SaveDataStorageGalaxyStage> {
+        self.galaxy.iter()
+    }
+
+    /// Returns an iterator that allows modifying each galaxy state.
+    pub fn iter_mut(&mut self) -> slice::IterMut<'_, SaveDataStorageGalaxyStage> {
+        self.galaxy.iter_mut()
+    }
+
     /// Returns a reference to the [`SaveDataStorageGalaxyStage`] corresponding to the key.
     pub fn get(&self, galaxy_name: impl Into<HashCode>) -> Option<&SaveDataStorageGalaxyStage> {
         let galaxy_name = HashCode16::from(galaxy_name.into());
@@ -53,6 +82,87 @@ impl SaveDataStorageGalaxy {
             .iter_mut()
             .find(|v| v.galaxy_name == galaxy_name)
     }
+
+    /// Returns a mutable reference to the [`SaveDataStorageGalaxyStage`] corresponding to the key,
+    /// inserting a closed galaxy state with `scenario_num` unplayed mission states if missing.
+    ///
+    /// The number of missions of each galaxy is not catalogued, so it is
+    /// given by `scenario_num`. The optional fields of the inserted mission
+    /// states are present if described by
+    /// [`dyn_scenario_serializer`](Self::dyn_scenario_serializer).
+    pub fn insert(
+        &mut self,
+        galaxy_name: impl Into<HashCode>,
+        scenario_num: u8,
+    ) -> &mut SaveDataStorageGalaxyStage {
+        let galaxy_name = HashCode16::from(galaxy_name.into());
+
+        let index = match self
+            .galaxy
+            .iter()
+            .position(|v| v.galaxy_name == galaxy_name)
+        {
+            Some(index) => index,
+            None => {
+                let serializer = self.dyn_scenario_serializer();
+                let mut stage = SaveDataStorageGalaxyStage::new(galaxy_name);
+
+                stage.scenario = (0..scenario_num)
+                    .map(|_| SaveDataStorageGalaxyScenario {
+                        clear_stage_num: serializer.contains("mClearStageNum").then_some(0),
+                        miss_stage_num: serializer.contains("mMissStageNum").then_some(0),
+                        total_play_second: serializer.contains("mTotalPlaySecond").then_some(0),
+                        ..SaveDataStorageGalaxyScenario::new()
+                    })
+                    .collect();
+
+                self.galaxy.push(stage);
+                self.galaxy.len() - 1
+            }
+        };
+
+        &mut self.galaxy[index]
+    }
+
+    /// Removes the [`SaveDataStorageGalaxyStage`] corresponding to the key, returning it if present.
+    pub fn remove(
+        &mut self,
+        galaxy_name: impl Into<HashCode>,
+    ) -> Option<SaveDataStorageGalaxyStage> {
+        let galaxy_name = HashCode16::from(galaxy_name.into());
+        let index = self
+            .galaxy
+            .iter()
+            .position(|v| v.galaxy_name == galaxy_name)?;
+
+        Some(self.galaxy.remove(index))
+    }
+
+    /// Creates the dynamic reader/writer for the mission states.
+    ///
+    /// The dynamic reader/writer read from the file is kept, extended with
+    /// the optional fields present in any mission state which it lacks. A
+    /// container not read from a file describes only the optional fields
+    /// present in any mission state.
+    pub fn dyn_scenario_serializer(
+        &self,
+    ) -> BinaryDataContentHeaderSerializer<SaveDataStorageGalaxyScenario> {
+        let present = |f: fn(&SaveDataStorageGalaxyScenario) -> bool| {
+            self.galaxy.iter().flat_map(|g| &g.scenario).any(f)
+        };
+        let serializer = SaveDataStorageGalaxyScenario {
+            clear_stage_num: present(|s| s.clear_stage_num.is_some()).then_some(0),
+            miss_stage_num: present(|s| s.miss_stage_num.is_some()).then_some(0),
+            total_play_second: present(|s| s.total_play_second.is_some()).then_some(0),
+            ..SaveDataStorageGalaxyScenario::new()
+        }
+        .dyn_header_serializer();
+
+        match &self.scenario_serializer {
+            Some(read) => read.widen(&serializer),
+            None => serializer,
+        }
+    }
 }
 
 impl Chunk for SaveDataStorageGalaxy {
@@ -63,22 +173,41 @@ impl Chunk for SaveDataStorageGalaxy {
 
         HashCode::from_raw(hash)
     }
+
+    fn dyn_hash_code(&self, _endian: Endian) -> HashCode {
+        let hash = self.dyn_scenario_serializer().data_size() as u32
+            + SaveDataStorageGalaxyStage::header_size() as u32
+            + 2;
+
+        HashCode::from_raw(hash)
+    }
 }
 
 /// A container for the state of a galaxy.
+///
+/// The size and number of mission states are computed when written. The
+/// header serializer is derived before the fields are removed by `binrw`, so
+/// it still describes them.
+#[derive(HeaderSerializer)]
 #[binrw]
+#[brw(import(scenario_serializer: &BinaryDataContentHeaderSerializer<SaveDataStorageGalaxyScenario>))]
 #[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
-#[derive(Debug, HeaderSerializer)]
+#[derive(Debug)]
 pub struct SaveDataStorageGalaxyStage {
     /// The hashed internal name of the galaxy, truncated to the least significant 16 bits.
+    #[cfg_attr(feature = "serde", serde(with = "crate::catalog::serde_galaxy_name"))]
     #[header_serializer(name = "mGalaxyName")]
     galaxy_name: HashCode16,
 
     /// The size of the serialized struct, in bytes.
+    #[br(temp)]
+    #[bw(calc = (Self::data_size() + scenario.len() * scenario_serializer.data_size()) as u16)]
     #[header_serializer(name = "mDataSize")]
     data_size: u16,
 
     /// The number of stored mission states.
+    #[br(temp)]
+    #[bw(calc = scenario.len() as u8)]
     #[header_serializer(name = "mScenarioNum")]
     scenario_num: u8,
 
@@ -91,16 +220,39 @@ pub struct SaveDataStorageGalaxyStage {
     pub flag: SaveDataStorageGalaxyFlag,
 
     /// The collection of mission states.
-    #[br(count = scenario_num as usize)]
+    #[br(count = scenario_num as usize, args { inner: (scenario_serializer,) })]
+    #[bw(args(scenario_serializer))]
     #[header_serializer(skip)]
     pub scenario: Vec<SaveDataStorageGalaxyScenario>,
 }
 
+impl SaveDataStorageGalaxyStage {
+    /// Creates a new `SaveDataStorageGalaxyStage` for a closed galaxy without mission states.
+    pub fn new(galaxy_name: impl Into<HashCode>) -> Self {
+        Self {
+            galaxy_name: HashCode16::from(galaxy_name.into()),
+            galaxy_state: SaveDataStorageGalaxyState::Closed,
+            flag: Default::default(),
+            scenario: Vec::new(),
+        }
+    }
+
+    /// Returns the hashed internal name of the galaxy.
+    pub const fn galaxy_name(&self) -> HashCode16 {
+        self.galaxy_name
+    }
+
+    /// Returns the catalog entry of the galaxy, if known.
+    pub fn galaxy(&self) -> Option<Galaxy> {
+        Galaxy::from_hash_code(self.galaxy_name)
+    }
+}
+
 /// The unit state of a galaxy on the World Map.
 #[binrw]
 #[brw(repr(u8))]
 #[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
-#[derive(Debug, Default)]
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
 pub enum SaveDataStorageGalaxyState {
     /// The galaxy is not available.
     #[default]
@@ -130,7 +282,11 @@ pub struct SaveDataStorageGalaxyFlag {
 }
 
 /// A container for the state of a mission.
-#[binrw]
+///
+/// Each field is read from and written to its offset described by the dynamic
+/// reader/writer shared by every mission state. Fields it does not describe
+/// are absent when read and not written, and bytes it describes for unknown
+/// fields are written as zero.
 #[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
 #[derive(Debug, Default, HeaderSerializer)]
 pub struct SaveDataStorageGalaxyScenario {
@@ -145,19 +301,109 @@ pub struct SaveDataStorageGalaxyScenario {
     /// The collection of packed binary settings.
     #[header_serializer(name = "mFlag")]
     pub flag: SaveDataStorageGalaxyScenarioFlag,
-    /*
+
     /// The number of times cleared.
+    #[cfg_attr(
+        feature = "serde",
+        serde(default, skip_serializing_if = "Option::is_none")
+    )]
     #[header_serializer(name = "mClearStageNum")]
-    pub clear_stage_num: u16,
+    pub clear_stage_num: Option<u16>,
 
     // TODO: Unused?
+    #[cfg_attr(
+        feature = "serde",
+        serde(default, skip_serializing_if = "Option::is_none")
+    )]
     #[header_serializer(name = "mMissStageNum")]
-    pub miss_stage_num: u16,
+    pub miss_stage_num: Option<u16>,
 
     /// The amount of time spent playing, in seconds.
+    #[cfg_attr(
+        feature = "serde",
+        serde(default, skip_serializing_if = "Option::is_none")
+    )]
     #[header_serializer(name = "mTotalPlaySecond")]
-    pub total_play_second: u16,
-    */
+    pub total_play_second: Option<u16>,
+}
+
+impl BinRead for SaveDataStorageGalaxyScenario {
+    type Args<'a> = (&'a BinaryDataContentHeaderSerializer<Self>,);
+
+    fn read_options<R: Read + Seek>(
+        reader: &mut R,
+        endian: Endian,
+        (serializer,): Self::Args<'_>,
+    ) -> BinResult<Self> {
+        let start = reader.stream_position()?;
+        let scenario = Self {
+            miss_num: serializer
+                .read_field("mMissNum", reader, endian, start)?
+                .unwrap_or_default(),
+            best_time: serializer
+                .read_field("mBestTime", reader, endian, start)?
+                .unwrap_or_default(),
+            flag: serializer
+                .read_field("mFlag", reader, endian, start)?
+                .unwrap_or_default(),
+            clear_stage_num: serializer.read_field("mClearStageNum", reader, endian, start)?,
+            miss_stage_num: serializer.read_field("mMissStageNum", reader, endian, start)?,
+            total_play_second: serializer.read_field("mTotalPlaySecond", reader, endian, start)?,
+        };
+
+        reader.seek(SeekFrom::Start(start + serializer.data_size() as u64))?;
+
+        Ok(scenario)
+    }
+}
+
+impl BinWrite for SaveDataStorageGalaxyScenario {
+    type Args<'a> = (&'a BinaryDataContentHeaderSerializer<Self>,);
+
+    fn write_options<W: Write + Seek>(
+        &self,
+        writer: &mut W,
+        endian: Endian,
+        (serializer,): Self::Args<'_>,
+    ) -> BinResult<()> {
+        let start = writer.stream_position()?;
+
+        vec![0u8; serializer.data_size()].write_options(writer, endian, ())?;
+        serializer.write_field("mMissNum", Some(&self.miss_num), writer, endian, start)?;
+        serializer.write_field("mBestTime", Some(&self.best_time), writer, endian, start)?;
+        serializer.write_field("mFlag", Some(&self.flag), writer, endian, start)?;
+        serializer.write_field(
+            "mClearStageNum",
+            self.clear_stage_num.as_ref(),
+            writer,
+            endian,
+            start,
+        )?;
+        serializer.write_field(
+            "mMissStageNum",
+            self.miss_stage_num.as_ref(),
+            writer,
+            endian,
+            start,
+        )?;
+        serializer.write_field(
+            "mTotalPlaySecond",
+            self.total_play_second.as_ref(),
+            writer,
+            endian,
+            start,
+        )?;
+        writer.seek(SeekFrom::Start(start + serializer.data_size() as u64))?;
+
+        Ok(())
+    }
+}
+
+impl SaveDataStorageGalaxyScenario {
+    /// Creates a new `SaveDataStorageGalaxyScenario` for an unplayed mission without optional fields.
+    pub fn new() -> Self {
+        Self::default()
+    }
 }
 
 /// The collection of packed binary settings for a mission.
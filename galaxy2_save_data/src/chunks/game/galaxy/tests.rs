@@ -0,0 +1,230 @@
+use binrw::{BinRead, BinWrite, Endian, io::Cursor};
+use galaxy_save_core::bin::{BinaryDataChunk, BinaryDataContentAttribute};
+
+use super::*;
+use crate::chunks::game::GameDataChunk;
+
+/// Writes a container for galaxy state and reads it back.
+fn round_trip(storage: &SaveDataStorageGalaxy) -> (SaveDataStorageGalaxy, Vec<u8>) {
+    let mut writer = Cursor::new(Vec::new());
+    storage.write_be(&mut writer).unwrap();
+
+    let bytes = writer.into_inner();
+    let storage =
+        SaveDataStorageGalaxy::read_be_args(&mut Cursor::new(&bytes), (bytes.len(),)).unwrap();
+
+    (storage, bytes)
+}
+
+/// Returns the descriptor for the field of the serialized name at the offset.
+fn attribute(name: &str, offset: u16) -> BinaryDataContentAttribute {
+    BinaryDataContentAttribute {
+        key: HashCode16::from(name),
+        offset,
+    }
+}
+
+/// Returns the dynamic reader/writer for mission states storing the total
+/// play time before the number of clears, unlike the declaration order.
+fn reordered_serializer() -> BinaryDataContentHeaderSerializer<SaveDataStorageGalaxyScenario> {
+    BinaryDataContentHeaderSerializer::new(
+        vec![
+            attribute("mMissNum", 0),
+            attribute("mBestTime", 1),
+            attribute("mFlag", 5),
+            attribute("mTotalPlaySecond", 6),
+            attribute("mClearStageNum", 8),
+        ],
+        10,
+    )
+}
+
+#[test]
+fn test_stage_header() {
+    let serializer = SaveDataStorageGalaxyStage::header_serializer();
+
+    assert!(serializer.contains("mDataSize"));
+    assert!(serializer.contains("mScenarioNum"));
+    assert_eq!(SaveDataStorageGalaxyStage::data_size(), 7);
+}
+
+#[test]
+fn test_write_computed_size() {
+    let mut storage = SaveDataStorageGalaxy::default();
+    let stage = storage.insert(Galaxy::IslandFleetGalaxy, 3);
+    stage.scenario[1].flag.set_power_star(true);
+
+    let scenario_size = storage.dyn_scenario_serializer().data_size();
+    let (read, bytes) = round_trip(&storage);
+    let stage_pos = bytes.len() - (7 + 3 * scenario_size);
+
+    assert_eq!(
+        u16::from_be_bytes([bytes[stage_pos + 2], bytes[stage_pos + 3]]) as usize,
+        7 + 3 * scenario_size
+    );
+    assert_eq!(bytes[stage_pos + 4], 3);
+
+    let stage = read.get(Galaxy::IslandFleetGalaxy).unwrap();
+
+    assert_eq!(stage.scenario.len(), 3);
+    assert!(stage.scenario[1].flag.power_star());
+}
+
+#[test]
+fn test_write_keeps_optional_fields() {
+    let mut storage = SaveDataStorageGalaxy::default();
+    let stage = storage.insert(Galaxy::IslandFleetGalaxy, 2);
+    stage.scenario[1].clear_stage_num = Some(5);
+
+    let (read, _) = round_trip(&storage);
+    let scenario = &read.get(Galaxy::IslandFleetGalaxy).unwrap().scenario;
+
+    assert_eq!(scenario[0].clear_stage_num, Some(0));
+    assert_eq!(scenario[1].clear_stage_num, Some(5));
+    assert_eq!(scenario[1].total_play_second, None);
+}
+
+#[test]
+fn test_insert_existing() {
+    let mut storage = SaveDataStorageGalaxy::default();
+    storage.insert(Galaxy::IslandFleetGalaxy, 2).galaxy_state = SaveDataStorageGalaxyState::New;
+
+    let stage = storage.insert(Galaxy::IslandFleetGalaxy, 5);
+
+    assert_eq!(stage.galaxy_state, SaveDataStorageGalaxyState::New);
+    assert_eq!(stage.scenario.len(), 2);
+    assert_eq!(storage.iter().count(), 1);
+}
+
+#[test]
+fn test_insert_optional_fields() {
+    let mut storage = SaveDataStorageGalaxy::default();
+    storage.insert(Galaxy::IslandFleetGalaxy, 1).scenario[0].total_play_second = Some(60);
+
+    let stage = storage.insert(Galaxy::YosshiHomeGalaxy, 2);
+
+    assert_eq!(stage.galaxy_state, SaveDataStorageGalaxyState::Closed);
+    assert!(
+        stage
+            .scenario
+            .iter()
+            .all(|s| s.total_play_second == Some(0))
+    );
+    assert!(stage.scenario.iter().all(|s| s.clear_stage_num.is_none()));
+}
+
+#[test]
+fn test_remove() {
+    let mut storage = SaveDataStorageGalaxy::default();
+    storage.insert(Galaxy::IslandFleetGalaxy, 1);
+    storage.insert(Galaxy::YosshiHomeGalaxy, 1);
+
+    let stage = storage.remove(Galaxy::IslandFleetGalaxy).unwrap();
+
+    assert_eq!(stage.galaxy(), Some(Galaxy::IslandFleetGalaxy));
+    assert!(storage.remove(Galaxy::IslandFleetGalaxy).is_none());
+    assert!(storage.get(Galaxy::IslandFleetGalaxy).is_none());
+    assert!(storage.get(Galaxy::YosshiHomeGalaxy).is_some());
+}
+
+#[test]
+fn test_hash_code_optional_fields() {
+    let mut storage = SaveDataStorageGalaxy::default();
+    storage.insert(Galaxy::IslandFleetGalaxy, 1);
+
+    assert_eq!(
+        storage.dyn_hash_code(Endian::Big),
+        SaveDataStorageGalaxy::hash_code()
+    );
+
+    storage.insert(Galaxy::YosshiHomeGalaxy, 1).scenario[0].miss_stage_num = Some(2);
+
+    assert_eq!(
+        storage.dyn_hash_code(Endian::Big).into_raw(),
+        SaveDataStorageGalaxy::hash_code().into_raw() + 2
+    );
+}
+
+#[test]
+fn test_read_scenario_by_offset() {
+    let serializer = reordered_serializer();
+    let bytes = [1, 0, 0, 0, 2, 0b1, 0, 60, 0, 5];
+
+    let scenario =
+        SaveDataStorageGalaxyScenario::read_be_args(&mut Cursor::new(&bytes), (&serializer,))
+            .unwrap();
+
+    assert_eq!(scenario.miss_num, 1);
+    assert_eq!(scenario.best_time, 2);
+    assert!(scenario.flag.power_star());
+    assert_eq!(scenario.total_play_second, Some(60));
+    assert_eq!(scenario.clear_stage_num, Some(5));
+    assert_eq!(scenario.miss_stage_num, None);
+
+    let mut writer = Cursor::new(Vec::new());
+    scenario.write_be_args(&mut writer, (&serializer,)).unwrap();
+
+    assert_eq!(writer.into_inner(), bytes);
+}
+
+#[test]
+fn test_read_scenario_skips_unknown_fields() {
+    let mut serializer = reordered_serializer();
+    serializer.attributes.push(attribute("mUnknown", 10));
+
+    let serializer = BinaryDataContentHeaderSerializer::new(serializer.attributes, 12);
+    let mut reader = Cursor::new([0; 12]);
+
+    SaveDataStorageGalaxyScenario::read_be_args(&mut reader, (&serializer,)).unwrap();
+
+    assert_eq!(reader.position(), 12);
+}
+
+#[test]
+fn test_write_keeps_read_serializer() {
+    let mut storage = SaveDataStorageGalaxy::default();
+    storage.insert(Galaxy::IslandFleetGalaxy, 1).scenario[0].clear_stage_num = Some(3);
+
+    let (mut read, _) = round_trip(&storage);
+    read.remove(Galaxy::IslandFleetGalaxy);
+
+    let chunk = GameDataChunk::Galaxy(BinaryDataChunk { inner: read });
+    let mut writer = Cursor::new(Vec::new());
+    chunk.write_be(&mut writer).unwrap();
+
+    let bytes = writer.into_inner();
+    let GameDataChunk::Galaxy(chunk) = GameDataChunk::read_be(&mut Cursor::new(&bytes)).unwrap()
+    else {
+        panic!("expected galaxy state");
+    };
+
+    assert!(
+        chunk
+            .inner
+            .dyn_scenario_serializer()
+            .contains("mClearStageNum")
+    );
+    assert_eq!(chunk.inner.iter().count(), 0);
+}
+
+#[test]
+fn test_write_widens_read_serializer() {
+    let mut storage = SaveDataStorageGalaxy {
+        scenario_serializer: Some(reordered_serializer()),
+        ..Default::default()
+    };
+    storage.insert(Galaxy::IslandFleetGalaxy, 1).scenario[0].miss_stage_num = Some(4);
+
+    let serializer = storage.dyn_scenario_serializer();
+
+    assert_eq!(serializer.offset_of("mTotalPlaySecond"), Some(6));
+    assert_eq!(serializer.offset_of("mClearStageNum"), Some(8));
+    assert_eq!(serializer.offset_of("mMissStageNum"), Some(10));
+    assert_eq!(serializer.data_size(), 12);
+
+    let (read, _) = round_trip(&storage);
+    let scenario = &read.get(Galaxy::IslandFleetGalaxy).unwrap().scenario[0];
+
+    assert_eq!(scenario.total_play_second, Some(0));
+    assert_eq!(scenario.miss_stage_num, Some(4));
+}
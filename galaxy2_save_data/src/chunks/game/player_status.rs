@@ -1,7 +1,9 @@
 //! Types associated with player state.
 
+use std::io::{Read, Seek, SeekFrom, Write};
+
 use bilge::prelude::*;
-use binrw::binrw;
+use binrw::{BinRead, BinResult, BinWrite, Endian, binrw};
 use galaxy_save_core::{
     bin::{BinaryDataContentHeaderSerializer, Chunk, HeaderSerializer},
     hash::HashCode,
@@ -10,15 +12,22 @@ use galaxy_save_core::{
 #[cfg(feature = "serde")]
 use serde::{Deserialize, Serialize};
 
+#[cfg(test)]
+mod tests;
+
 /// A container for player state.
-#[binrw]
-#[br(import(_data_size: usize))]
+///
+/// Each field is read from and written to its offset described by the dynamic
+/// reader/writer preceding the fields. Fields it does not describe are absent
+/// when read and not written, and bytes it describes for unknown fields are
+/// written as zero.
 #[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
 #[derive(Debug, HeaderSerializer)]
 pub struct SaveDataStoragePlayerStatus {
-    #[br(temp)]
-    #[bw(calc = Self::header_serializer())]
-    _serializer: BinaryDataContentHeaderSerializer<Self>,
+    /// The dynamic reader/writer read from the file, if any.
+    #[cfg_attr(feature = "serde", serde(skip))]
+    #[header_serializer(skip)]
+    serializer: Option<BinaryDataContentHeaderSerializer<Self>>,
 
     /// The number of remaining lives.
     #[header_serializer(name = "mPlayerLeft")]
@@ -39,79 +48,125 @@ pub struct SaveDataStoragePlayerStatus {
     /// The collection of packed binary settings.
     #[header_serializer(name = "mFlag")]
     pub flag: SaveDataStoragePlayerStatusFlag,
-    /*
+
     /// The number of scanned amiibo.
+    #[cfg_attr(
+        feature = "serde",
+        serde(default, skip_serializing_if = "Option::is_none")
+    )]
     #[header_serializer(name = "mAmiiboScanNum")]
-    pub amiibo_scan_num: u8,
+    pub amiibo_scan_num: Option<u8>,
 
     /// The index of the Banktoad's active accessory.
+    #[cfg_attr(
+        feature = "serde",
+        serde(default, skip_serializing_if = "Option::is_none")
+    )]
     #[header_serializer(name = "mBankToadToolIndex")]
-    pub bank_toad_tool_index: u8,
+    pub bank_toad_tool_index: Option<u8>,
 
     /// Determines if Rosalina's Storybook was opened.
+    #[cfg_attr(
+        feature = "serde",
+        serde(default, skip_serializing_if = "Option::is_none")
+    )]
     #[header_serializer(name = "mIsPictureBookOpened")]
-    pub is_picture_book_opened: bool,
+    pub is_picture_book_opened: Option<bool>,
 
     /// The number of cutscenes skipped.
+    #[cfg_attr(
+        feature = "serde",
+        serde(default, skip_serializing_if = "Option::is_none")
+    )]
     #[header_serializer(name = "mDemoSkipNum")]
-    pub demo_skip_num: u16,
+    pub demo_skip_num: Option<u16>,
 
     /// The amount of time spent on the Original Soundtrack screen, in seconds.
+    #[cfg_attr(
+        feature = "serde",
+        serde(default, skip_serializing_if = "Option::is_none")
+    )]
     #[header_serializer(name = "mMusicPlaySeconds")]
-    pub music_play_seconds: u16,
+    pub music_play_seconds: Option<u16>,
 
     /// The number of missions cleared.
+    #[cfg_attr(
+        feature = "serde",
+        serde(default, skip_serializing_if = "Option::is_none")
+    )]
     #[header_serializer(name = "mPlayNum")]
-    pub play_num: u16,
+    pub play_num: Option<u16>,
 
     /// The number of missions cleared with Co-Star Mode enabled.
+    #[cfg_attr(
+        feature = "serde",
+        serde(default, skip_serializing_if = "Option::is_none")
+    )]
     #[header_serializer(name = "m2pNum")]
-    pub p2_num: u16,
+    pub p2_num: Option<u16>,
 
     /// The number of missions cleared with Luigi.
+    #[cfg_attr(
+        feature = "serde",
+        serde(default, skip_serializing_if = "Option::is_none")
+    )]
     #[header_serializer(name = "mLuigiNum")]
-    pub luigi_num: u16,
+    pub luigi_num: Option<u16>,
 
     /// The amount of time taken to partially complete the game, in seconds.
+    #[cfg_attr(
+        feature = "serde",
+        serde(default, skip_serializing_if = "Option::is_none")
+    )]
     #[header_serializer(name = "mGameFinishTime")]
-    pub game_finish_time: u32,
+    pub game_finish_time: Option<u32>,
 
     /// The collection of packed binary settings for defeated boss state.
+    #[cfg_attr(
+        feature = "serde",
+        serde(default, skip_serializing_if = "Option::is_none")
+    )]
     #[header_serializer(name = "mBossesFinishedFlag")]
-    pub bosses_finished_flag: SaveDataStoragePlayerStatusBossesFinishedFlag,
+    pub bosses_finished_flag: Option<SaveDataStoragePlayerStatusBossesFinishedFlag>,
 
     // TODO: Unused?
+    #[cfg_attr(
+        feature = "serde",
+        serde(default, skip_serializing_if = "Option::is_none")
+    )]
     #[header_serializer(name = "mNpcConversationFlag")]
-    pub npc_conversation_flag: u32,
+    pub npc_conversation_flag: Option<u32>,
 
     /// Determines if Assist Mode is enabled.
+    #[cfg_attr(
+        feature = "serde",
+        serde(default, skip_serializing_if = "Option::is_none")
+    )]
     #[header_serializer(name = "mIsAssistMode")]
-    pub is_assist_mode: bool,
-    */
+    pub is_assist_mode: Option<bool>,
 }
 
 impl Default for SaveDataStoragePlayerStatus {
     fn default() -> Self {
         Self {
+            serializer: None,
             player_left: 4,
             stocked_star_piece_num: 0,
             stocked_coin_num: 0,
             last_1up_coin_num: 0,
             flag: Default::default(),
-            /*
-            amiibo_scan_num: 0,
-            bank_toad_tool_index: 0,
-            is_picture_book_opened: false,
-            demo_skip_num: 0,
-            music_play_seconds: 0,
-            play_num: 0,
-            p2_num: 0,
-            luigi_num: 0,
-            game_finish_time: 0,
-            bosses_finished_flag: Default::default(),
-            npc_conversation_flag: 0,
-            is_assist_mode: false,
-            */
+            amiibo_scan_num: None,
+            bank_toad_tool_index: None,
+            is_picture_book_opened: None,
+            demo_skip_num: None,
+            music_play_seconds: None,
+            play_num: None,
+            p2_num: None,
+            luigi_num: None,
+            game_finish_time: None,
+            bosses_finished_flag: None,
+            npc_conversation_flag: None,
+            is_assist_mode: None,
         }
     }
 }
@@ -122,6 +177,230 @@ impl Chunk for SaveDataStoragePlayerStatus {
 
         HashCode::from_raw(hash)
     }
+
+    fn dyn_hash_code(&self, _endian: Endian) -> HashCode {
+        let serializer = self.written_serializer();
+        let hash = serializer.data_size() as u32 + serializer.header_size() as u32;
+
+        HashCode::from_raw(hash)
+    }
+}
+
+impl SaveDataStoragePlayerStatus {
+    /// Creates the dynamic reader/writer written for the player state.
+    ///
+    /// The dynamic reader/writer read from the file is kept, extended with
+    /// the optional fields present in `self` which it lacks. A player state
+    /// not read from a file describes only the fields present in `self`.
+    pub fn written_serializer(&self) -> BinaryDataContentHeaderSerializer<Self> {
+        let serializer = self.dyn_header_serializer();
+
+        match &self.serializer {
+            Some(read) => read.widen(&serializer),
+            None => serializer,
+        }
+    }
+}
+
+impl BinRead for SaveDataStoragePlayerStatus {
+    type Args<'a> = (usize,);
+
+    fn read_options<R: Read + Seek>(
+        reader: &mut R,
+        endian: Endian,
+        _args: Self::Args<'_>,
+    ) -> BinResult<Self> {
+        let serializer =
+            BinaryDataContentHeaderSerializer::<Self>::read_options(reader, endian, ())?;
+        let start = reader.stream_position()?;
+        let default = Self::default();
+        let is_true = |b: Option<u8>| b.map(|b| b != 0);
+        let status = Self {
+            player_left: serializer
+                .read_field("mPlayerLeft", reader, endian, start)?
+                .unwrap_or(default.player_left),
+            stocked_star_piece_num: serializer
+                .read_field("mStockedStarPieceNum", reader, endian, start)?
+                .unwrap_or(default.stocked_star_piece_num),
+            stocked_coin_num: serializer
+                .read_field("mStockedCoinNum", reader, endian, start)?
+                .unwrap_or(default.stocked_coin_num),
+            last_1up_coin_num: serializer
+                .read_field("mLast1upCoinNum", reader, endian, start)?
+                .unwrap_or(default.last_1up_coin_num),
+            flag: serializer
+                .read_field("mFlag", reader, endian, start)?
+                .unwrap_or(default.flag),
+            amiibo_scan_num: serializer.read_field("mAmiiboScanNum", reader, endian, start)?,
+            bank_toad_tool_index: serializer.read_field(
+                "mBankToadToolIndex",
+                reader,
+                endian,
+                start,
+            )?,
+            is_picture_book_opened: is_true(serializer.read_field(
+                "mIsPictureBookOpened",
+                reader,
+                endian,
+                start,
+            )?),
+            demo_skip_num: serializer.read_field("mDemoSkipNum", reader, endian, start)?,
+            music_play_seconds: serializer.read_field(
+                "mMusicPlaySeconds",
+                reader,
+                endian,
+                start,
+            )?,
+            play_num: serializer.read_field("mPlayNum", reader, endian, start)?,
+            p2_num: serializer.read_field("m2pNum", reader, endian, start)?,
+            luigi_num: serializer.read_field("mLuigiNum", reader, endian, start)?,
+            game_finish_time: serializer.read_field("mGameFinishTime", reader, endian, start)?,
+            bosses_finished_flag: serializer.read_field(
+                "mBossesFinishedFlag",
+                reader,
+                endian,
+                start,
+            )?,
+            npc_conversation_flag: serializer.read_field(
+                "mNpcConversationFlag",
+                reader,
+                endian,
+                start,
+            )?,
+            is_assist_mode: is_true(serializer.read_field(
+                "mIsAssistMode",
+                reader,
+                endian,
+                start,
+            )?),
+            serializer: None,
+        };
+
+        reader.seek(SeekFrom::Start(start + serializer.data_size() as u64))?;
+
+        Ok(Self {
+            serializer: Some(serializer),
+            ..status
+        })
+    }
+}
+
+impl BinWrite for SaveDataStoragePlayerStatus {
+    type Args<'a> = ();
+
+    fn write_options<W: Write + Seek>(
+        &self,
+        writer: &mut W,
+        endian: Endian,
+        _args: Self::Args<'_>,
+    ) -> BinResult<()> {
+        let serializer = self.written_serializer();
+
+        serializer.write_options(writer, endian, ())?;
+
+        let start = writer.stream_position()?;
+
+        vec![0u8; serializer.data_size()].write_options(writer, endian, ())?;
+
+        serializer.write_field(
+            "mPlayerLeft",
+            Some(&self.player_left),
+            writer,
+            endian,
+            start,
+        )?;
+        serializer.write_field(
+            "mStockedStarPieceNum",
+            Some(&self.stocked_star_piece_num),
+            writer,
+            endian,
+            start,
+        )?;
+        serializer.write_field(
+            "mStockedCoinNum",
+            Some(&self.stocked_coin_num),
+            writer,
+            endian,
+            start,
+        )?;
+        serializer.write_field(
+            "mLast1upCoinNum",
+            Some(&self.last_1up_coin_num),
+            writer,
+            endian,
+            start,
+        )?;
+        serializer.write_field("mFlag", Some(&self.flag), writer, endian, start)?;
+        serializer.write_field(
+            "mAmiiboScanNum",
+            self.amiibo_scan_num.as_ref(),
+            writer,
+            endian,
+            start,
+        )?;
+        serializer.write_field(
+            "mBankToadToolIndex",
+            self.bank_toad_tool_index.as_ref(),
+            writer,
+            endian,
+            start,
+        )?;
+        serializer.write_field(
+            "mIsPictureBookOpened",
+            self.is_picture_book_opened.map(u8::from).as_ref(),
+            writer,
+            endian,
+            start,
+        )?;
+        serializer.write_field(
+            "mDemoSkipNum",
+            self.demo_skip_num.as_ref(),
+            writer,
+            endian,
+            start,
+        )?;
+        serializer.write_field(
+            "mMusicPlaySeconds",
+            self.music_play_seconds.as_ref(),
+            writer,
+            endian,
+            start,
+        )?;
+        serializer.write_field("mPlayNum", self.play_num.as_ref(), writer, endian, start)?;
+        serializer.write_field("m2pNum", self.p2_num.as_ref(), writer, endian, start)?;
+        serializer.write_field("mLuigiNum", self.luigi_num.as_ref(), writer, endian, start)?;
+        serializer.write_field(
+            "mGameFinishTime",
+            self.game_finish_time.as_ref(),
+            writer,
+            endian,
+            start,
+        )?;
+        serializer.write_field(
+            "mBossesFinishedFlag",
+            self.bosses_finished_flag.as_ref(),
+            writer,
+            endian,
+            start,
+        )?;
+        serializer.write_field(
+            "mNpcConversationFlag",
+            self.npc_conversation_flag.as_ref(),
+            writer,
+            endian,
+            start,
+        )?;
+        serializer.write_field(
+            "mIsAssistMode",
+            self.is_assist_mode.map(u8::from).as_ref(),
+            writer,
+            endian,
+            start,
+        )?;
+        writer.seek(SeekFrom::Start(start + serializer.data_size() as u64))?;
+
+        Ok(())
+    }
 }
 
 /// A collection of packed binary settings for player state.
@@ -137,7 +416,6 @@ pub struct SaveDataStoragePlayerStatusFlag {
     reserved: u7,
 }
 
-/*
 /// A collection of packed binary settings for defeated boss state.
 #[bitsize(32)]
 #[binrw]
@@ -216,4 +494,3 @@ pub struct SaveDataStoragePlayerStatusBossesFinishedFlag {
 
     reserved: u9,
 }
-*/
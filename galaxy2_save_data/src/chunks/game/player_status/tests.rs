@@ -0,0 +1,193 @@
+use binrw::{BinRead, BinWrite, io::Cursor};
+use galaxy_save_core::{bin::BinaryDataContentAttribute, hash::HashCode16};
+
+use super::*;
+
+/// Writes a container for player state and reads it back.
+fn round_trip(status: &SaveDataStoragePlayerStatus) -> (SaveDataStoragePlayerStatus, Vec<u8>) {
+    let mut writer = Cursor::new(Vec::new());
+    status.write_be(&mut writer).unwrap();
+
+    let bytes = writer.into_inner();
+    let status =
+        SaveDataStoragePlayerStatus::read_be_args(&mut Cursor::new(&bytes), (bytes.len(),))
+            .unwrap();
+
+    (status, bytes)
+}
+
+/// Returns the descriptor for the field of the serialized name at the offset.
+fn attribute(name: &str, offset: u16) -> BinaryDataContentAttribute {
+    BinaryDataContentAttribute {
+        key: HashCode16::from(name),
+        offset,
+    }
+}
+
+/// Returns the dynamic reader/writer for player state storing the play time
+/// before the number of lives, unlike the declaration order.
+fn reordered_serializer() -> BinaryDataContentHeaderSerializer<SaveDataStoragePlayerStatus> {
+    BinaryDataContentHeaderSerializer::new(
+        vec![
+            attribute("mGameFinishTime", 0),
+            attribute("mPlayerLeft", 4),
+            attribute("mStockedStarPieceNum", 5),
+            attribute("mStockedCoinNum", 7),
+            attribute("mLast1upCoinNum", 9),
+            attribute("mFlag", 11),
+            attribute("mUnknown", 12),
+        ],
+        14,
+    )
+}
+
+/// Writes the dynamic reader/writer followed by the field bytes.
+fn with_serializer(
+    serializer: &BinaryDataContentHeaderSerializer<SaveDataStoragePlayerStatus>,
+    data: &[u8],
+) -> Vec<u8> {
+    let mut writer = Cursor::new(Vec::new());
+    serializer.write_be(&mut writer).unwrap();
+
+    let mut bytes = writer.into_inner();
+    bytes.extend_from_slice(data);
+    bytes
+}
+
+#[test]
+fn test_round_trip_base() {
+    let status = SaveDataStoragePlayerStatus {
+        stocked_star_piece_num: 300,
+        ..Default::default()
+    };
+
+    let (read, bytes) = round_trip(&status);
+
+    assert_eq!(
+        bytes.len(),
+        status.dyn_header_size() + status.dyn_data_size()
+    );
+    assert_eq!(status.dyn_data_size(), 8);
+    assert_eq!(read.player_left, 4);
+    assert_eq!(read.stocked_star_piece_num, 300);
+    assert!(read.amiibo_scan_num.is_none());
+    assert!(read.is_assist_mode.is_none());
+}
+
+#[test]
+fn test_round_trip_optional_fields() {
+    let status = SaveDataStoragePlayerStatus {
+        is_picture_book_opened: Some(true),
+        demo_skip_num: Some(12),
+        game_finish_time: Some(36000),
+        is_assist_mode: Some(false),
+        ..Default::default()
+    };
+
+    let (read, bytes) = round_trip(&status);
+
+    assert!(status.dyn_header_serializer().contains("mIsAssistMode"));
+    assert!(!status.dyn_header_serializer().contains("mPlayNum"));
+    assert_eq!(
+        bytes.len(),
+        status.dyn_header_size() + status.dyn_data_size()
+    );
+    assert_eq!(read.is_picture_book_opened, Some(true));
+    assert_eq!(read.demo_skip_num, Some(12));
+    assert_eq!(read.game_finish_time, Some(36000));
+    assert_eq!(read.is_assist_mode, Some(false));
+    assert!(read.play_num.is_none());
+    assert_eq!(
+        read.dyn_hash_code(Endian::Big),
+        status.dyn_hash_code(Endian::Big)
+    );
+    assert_ne!(
+        read.dyn_hash_code(Endian::Big),
+        SaveDataStoragePlayerStatus::hash_code()
+    );
+}
+
+#[test]
+fn test_read_truncated() {
+    let status = SaveDataStoragePlayerStatus {
+        game_finish_time: Some(36000),
+        ..Default::default()
+    };
+
+    let mut writer = Cursor::new(Vec::new());
+    status.write_be(&mut writer).unwrap();
+
+    let mut bytes = writer.into_inner();
+    bytes.truncate(bytes.len() - 1);
+
+    assert!(
+        SaveDataStoragePlayerStatus::read_be_args(&mut Cursor::new(&bytes), (bytes.len(),))
+            .is_err()
+    );
+}
+
+#[test]
+fn test_read_by_offset() {
+    let bytes = with_serializer(
+        &reordered_serializer(),
+        &[0, 0, 0x8C, 0xA0, 3, 1, 0x2C, 0, 50, 0, 100, 0, 0, 0],
+    );
+
+    let mut reader = Cursor::new(&bytes);
+    let status = SaveDataStoragePlayerStatus::read_be_args(&mut reader, (bytes.len(),)).unwrap();
+
+    assert_eq!(reader.position(), bytes.len() as u64);
+    assert_eq!(status.game_finish_time, Some(36000));
+    assert_eq!(status.player_left, 3);
+    assert_eq!(status.stocked_star_piece_num, 300);
+    assert_eq!(status.stocked_coin_num, 50);
+    assert_eq!(status.last_1up_coin_num, 100);
+    assert!(status.play_num.is_none());
+}
+
+#[test]
+fn test_write_keeps_read_serializer() {
+    let bytes = with_serializer(
+        &reordered_serializer(),
+        &[0, 0, 0x8C, 0xA0, 3, 1, 0x2C, 0, 50, 0, 100, 0, 0, 0],
+    );
+
+    let status =
+        SaveDataStoragePlayerStatus::read_be_args(&mut Cursor::new(&bytes), (bytes.len(),))
+            .unwrap();
+
+    let mut writer = Cursor::new(Vec::new());
+    status.write_be(&mut writer).unwrap();
+
+    assert_eq!(writer.into_inner(), bytes);
+    assert_eq!(
+        status.dyn_hash_code(Endian::Big),
+        HashCode::from_raw(bytes.len() as u32)
+    );
+}
+
+#[test]
+fn test_write_widens_read_serializer() {
+    let bytes = with_serializer(
+        &reordered_serializer(),
+        &[0, 0, 0x8C, 0xA0, 3, 1, 0x2C, 0, 50, 0, 100, 0, 0, 0],
+    );
+
+    let mut status =
+        SaveDataStoragePlayerStatus::read_be_args(&mut Cursor::new(&bytes), (bytes.len(),))
+            .unwrap();
+    status.is_assist_mode = Some(true);
+
+    let serializer = status.written_serializer();
+
+    assert_eq!(serializer.offset_of("mGameFinishTime"), Some(0));
+    assert_eq!(serializer.offset_of("mUnknown"), Some(12));
+    assert_eq!(serializer.offset_of("mIsAssistMode"), Some(14));
+    assert_eq!(serializer.data_size(), 15);
+
+    let (read, _) = round_trip(&status);
+
+    assert_eq!(read.is_assist_mode, Some(true));
+    assert_eq!(read.game_finish_time, Some(36000));
+    assert_eq!(read.stocked_star_piece_num, 300);
+}
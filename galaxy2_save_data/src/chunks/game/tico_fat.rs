@@ -1,21 +1,38 @@
 //! Types associated with Hungry Luma state.
 
-use binrw::binrw;
+use binrw::{Endian, binrw};
 use galaxy_save_core::{
     bin::Chunk,
     hash::{HashCode, HashCode16},
 };
+use thiserror::Error;
 
 #[cfg(feature = "serde")]
 use serde::{Deserialize, Serialize};
 
-use crate::chunks::game::SaveDataStorageWorldMap;
+use crate::{catalog::World, chunks::game::SaveDataStorageWorldMap};
+
+#[cfg(test)]
+mod tests;
 
 /// A container for Hungry Luma state.
+///
+/// When serialized, the Star Bit counters are named after their world, and
+/// each slot of the galaxies with fed coin-dependent Hungry Lumas is named
+/// after its galaxy, or `null` if empty. The unnamed matrix of Star Bit
+/// counters and the hexadecimal empty slots of earlier versions are also
+/// accepted when deserialized.
 #[binrw]
-#[br(import(_data_size: usize))]
+#[br(import(data_size: usize))]
 #[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
-#[derive(Debug, Default)]
+#[cfg_attr(
+    feature = "serde",
+    serde(
+        try_from = "SaveDataStorageTicoFatSerde",
+        into = "SaveDataStorageTicoFatSerde"
+    )
+)]
+#[derive(Debug, Clone, Default)]
 pub struct SaveDataStorageTicoFat {
     /// The matrix of Star Bit counters, where each row corresponds to a world
     /// and each column corresponds to an individual Hungry Luma's consumed
@@ -25,28 +42,51 @@ pub struct SaveDataStorageTicoFat {
     /// The array of hashed internal galaxy names with satisfied coin-dependent
     /// Hungry Lumas, truncated to the least significant 16 bits.
     coin_galaxy_name: [HashCode16; Self::COIN_GALAXY_NAME_NUM],
+
+    /// The data following the known fields in the Nintendo Switch layout,
+    /// preserved as is.
+    ///
+    /// The fields of this data are undocumented. Its presence determines the
+    /// hash digest of the data block.
+    #[br(count = data_size.saturating_sub(Self::DATA_SIZE))]
+    switch_data: Vec<u8>,
 }
 
 impl SaveDataStorageTicoFat {
     /// The maximum number of Hungry Lumas that can be stored in any one world.
-    const PARTS_NUM: usize = 6;
+    pub const PARTS_NUM: usize = 6;
 
     /// The maximum number of hashed internal galaxy names with satisfied coin-
     /// dependent Hungry Lumas that can be stored.
-    const COIN_GALAXY_NAME_NUM: usize = 16;
+    pub const COIN_GALAXY_NAME_NUM: usize = 16;
+
+    /// The serialized size of the known fields, in bytes.
+    const DATA_SIZE: usize = size_of::<
+        [[u16; Self::PARTS_NUM]; SaveDataStorageWorldMap::WORLD_CAPACITY],
+    >() + size_of::<[HashCode16; Self::COIN_GALAXY_NAME_NUM]>();
+
+    /// Determines if the data block follows the Nintendo Switch layout.
+    pub fn is_switch_layout(&self) -> bool {
+        !self.switch_data.is_empty()
+    }
 
-    /// Returns a reference to a Star Bit counter.
-    pub fn star_piece_num(&self, world_no: usize, parts_index: usize) -> Option<&u16> {
-        self.star_piece_num
-            .get(world_no - 1)
-            .and_then(|w| w.get(parts_index))
+    /// Returns the number of Star Bits consumed by the Hungry Luma.
+    pub fn star_piece_num(&self, luma: HungryLuma) -> u16 {
+        self.star_piece_num[luma.world.world_no() - 1][luma.index]
     }
 
-    /// Returns a mutable reference to a Star Bit counter.
-    pub fn star_piece_num_mut(&mut self, world_no: usize, parts_index: usize) -> Option<&mut u16> {
-        self.star_piece_num
-            .get_mut(world_no - 1)
-            .and_then(|w| w.get_mut(parts_index))
+    /// Returns a mutable reference to the number of Star Bits consumed by the Hungry Luma.
+    pub fn star_piece_num_mut(&mut self, luma: HungryLuma) -> &mut u16 {
+        &mut self.star_piece_num[luma.world.world_no() - 1][luma.index]
+    }
+
+    /// Returns an iterator over the hashed internal galaxy names with
+    /// satisfied coin-dependent Hungry Lumas.
+    pub fn coin_galaxy_names(&self) -> impl Iterator<Item = HashCode16> {
+        self.coin_galaxy_name
+            .iter()
+            .copied()
+            .filter(|h| h.into_raw() != 0)
     }
 
     /// Determines if a Hungry Luma in a galaxy was fed a satisfactory number of coins.
@@ -57,27 +97,41 @@ impl SaveDataStorageTicoFat {
     }
 
     /// Registers a galaxy as having a Hungry Luma fed a satisfactory number of coins.
-    pub fn on_coin_feed(&mut self, galaxy_name: impl Into<HashCode>) {
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if every galaxy name slot is occupied by another galaxy.
+    pub fn on_coin_feed(
+        &mut self,
+        galaxy_name: impl Into<HashCode>,
+    ) -> Result<(), CoinGalaxyCapacityError> {
         let galaxy_name = HashCode16::from(galaxy_name.into());
 
         if self.coin_galaxy_name.contains(&galaxy_name) {
-            return;
+            return Ok(());
         }
 
-        if let Some(hash) = self.coin_galaxy_name.iter_mut().find(|h| h.into_raw() == 0) {
-            *hash = galaxy_name;
-        }
+        let hash = self
+            .coin_galaxy_name
+            .iter_mut()
+            .find(|h| h.into_raw() == 0)
+            .ok_or(CoinGalaxyCapacityError)?;
+
+        *hash = galaxy_name;
+
+        Ok(())
     }
 
-    /// Omits a galaxy from having a Hungry Luma fed a satisfactory number of coins.
-    pub fn off_coin_feed(&mut self, galaxy_name: impl Into<HashCode>) {
+    /// Omits a galaxy from having a Hungry Luma fed a satisfactory number of
+    /// coins, returning `true` if the galaxy was registered.
+    pub fn off_coin_feed(&mut self, galaxy_name: impl Into<HashCode>) -> bool {
         let galaxy_name = HashCode16::from(galaxy_name.into());
         let Some(position) = self
             .coin_galaxy_name
             .iter_mut()
             .position(|h| *h == galaxy_name)
         else {
-            return;
+            return false;
         };
 
         self.coin_galaxy_name[position] = Default::default();
@@ -89,15 +143,200 @@ impl SaveDataStorageTicoFat {
 
             self.coin_galaxy_name.swap(i, i + 1);
         }
+
+        true
     }
-}
 
-impl Chunk for SaveDataStorageTicoFat {
-    fn hash_code() -> HashCode {
+    /// Returns the hash digest identifying the data block, offset by a platform-specific value.
+    fn hash_code_with_offset(offset: u32) -> HashCode {
         let hash = HashCode::from("SaveDataStorageTicoFat")
             .into_raw()
-            .wrapping_add(0x120); // TODO: 0x1E0 on Nintendo Switch
+            .wrapping_add(offset);
 
         HashCode::from_raw(hash)
     }
 }
+
+impl Chunk for SaveDataStorageTicoFat {
+    fn hash_code() -> HashCode {
+        Self::hash_code_with_offset(0x120)
+    }
+
+    fn dyn_hash_code(&self, _endian: Endian) -> HashCode {
+        if self.is_switch_layout() {
+            Self::hash_code_with_offset(0x1E0)
+        } else {
+            Self::hash_code()
+        }
+    }
+}
+
+/// A Hungry Luma of the World Map, identified by its world and its index
+/// within the world.
+///
+/// The Hungry Luma assigned to each index, along with its target galaxy and
+/// required number of Star Bits, is undocumented.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub struct HungryLuma {
+    world: World,
+    index: usize,
+}
+
+impl HungryLuma {
+    /// Creates a new `HungryLuma` if the index is within the capacity of a world.
+    pub const fn new(world: World, index: usize) -> Option<Self> {
+        if index < SaveDataStorageTicoFat::PARTS_NUM {
+            Some(Self { world, index })
+        } else {
+            None
+        }
+    }
+
+    /// Returns an iterator over every Hungry Luma that can be stored in the world.
+    pub fn all(world: World) -> impl Iterator<Item = Self> {
+        (0..SaveDataStorageTicoFat::PARTS_NUM).map(move |index| Self { world, index })
+    }
+
+    /// Returns the world of the Hungry Luma.
+    pub const fn world(self) -> World {
+        self.world
+    }
+
+    /// Returns the index of the Hungry Luma within its world.
+    pub const fn index(self) -> usize {
+        self.index
+    }
+}
+
+/// An error returned from registering a galaxy with every galaxy name slot occupied.
+#[derive(Debug, Error)]
+#[error(
+    "cannot register more than {} galaxies with fed Hungry Lumas",
+    SaveDataStorageTicoFat::COIN_GALAXY_NAME_NUM
+)]
+pub struct CoinGalaxyCapacityError;
+
+/// The serialized form of [`SaveDataStorageTicoFat`].
+#[cfg(feature = "serde")]
+#[derive(Serialize, Deserialize)]
+struct SaveDataStorageTicoFatSerde {
+    /// The Star Bit counters of each world.
+    star_piece_num: StarPieceNumForm,
+
+    /// The slots of the galaxies with satisfied coin-dependent Hungry Lumas,
+    /// in the order stored.
+    coin_galaxy_name: Vec<Option<GalaxyNameSerde>>,
+
+    /// The data following the known fields in the Nintendo Switch layout.
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    switch_data: Vec<u8>,
+}
+
+/// The serialized forms of the Star Bit counters.
+#[cfg(feature = "serde")]
+#[derive(Serialize, Deserialize)]
+#[serde(untagged)]
+enum StarPieceNumForm {
+    /// The counters named after their world.
+    Named(StarPieceNumSerde),
+
+    /// The matrix of counters, where each row corresponds to a world.
+    Matrix([[u16; SaveDataStorageTicoFat::PARTS_NUM]; SaveDataStorageWorldMap::WORLD_CAPACITY]),
+}
+
+/// The serialized form of the Star Bit counters, named after their world.
+#[cfg(feature = "serde")]
+#[derive(Serialize, Deserialize)]
+struct StarPieceNumSerde {
+    world_1: [u16; SaveDataStorageTicoFat::PARTS_NUM],
+    world_2: [u16; SaveDataStorageTicoFat::PARTS_NUM],
+    world_3: [u16; SaveDataStorageTicoFat::PARTS_NUM],
+    world_4: [u16; SaveDataStorageTicoFat::PARTS_NUM],
+    world_5: [u16; SaveDataStorageTicoFat::PARTS_NUM],
+    world_6: [u16; SaveDataStorageTicoFat::PARTS_NUM],
+    world_s: [u16; SaveDataStorageTicoFat::PARTS_NUM],
+
+    /// The Star Bit counters not associated with a world.
+    #[serde(default)]
+    unknown: [u16; SaveDataStorageTicoFat::PARTS_NUM],
+}
+
+/// The serialized form of a hashed internal galaxy name.
+#[cfg(feature = "serde")]
+#[derive(Serialize, Deserialize)]
+#[serde(transparent)]
+struct GalaxyNameSerde(#[serde(with = "crate::catalog::serde_galaxy_name")] HashCode16);
+
+#[cfg(feature = "serde")]
+impl TryFrom<SaveDataStorageTicoFatSerde> for SaveDataStorageTicoFat {
+    type Error = CoinGalaxyCapacityError;
+
+    fn try_from(value: SaveDataStorageTicoFatSerde) -> Result<Self, Self::Error> {
+        let star_piece_num = match value.star_piece_num {
+            StarPieceNumForm::Named(StarPieceNumSerde {
+                world_1,
+                world_2,
+                world_3,
+                world_4,
+                world_5,
+                world_6,
+                world_s,
+                unknown,
+            }) => [
+                world_1, world_2, world_3, world_4, world_5, world_6, world_s, unknown,
+            ],
+            StarPieceNumForm::Matrix(star_piece_num) => star_piece_num,
+        };
+
+        if value.coin_galaxy_name.len() > Self::COIN_GALAXY_NAME_NUM {
+            return Err(CoinGalaxyCapacityError);
+        }
+
+        let mut coin_galaxy_name = <[HashCode16; Self::COIN_GALAXY_NAME_NUM]>::default();
+
+        for (slot, name) in coin_galaxy_name.iter_mut().zip(value.coin_galaxy_name) {
+            *slot = name.map(|n| n.0).unwrap_or_default();
+        }
+
+        Ok(Self {
+            star_piece_num,
+            coin_galaxy_name,
+            switch_data: value.switch_data,
+        })
+    }
+}
+
+#[cfg(feature = "serde")]
+impl From<SaveDataStorageTicoFat> for SaveDataStorageTicoFatSerde {
+    fn from(value: SaveDataStorageTicoFat) -> Self {
+        let [
+            world_1,
+            world_2,
+            world_3,
+            world_4,
+            world_5,
+            world_6,
+            world_s,
+            unknown,
+        ] = value.star_piece_num;
+
+        Self {
+            star_piece_num: StarPieceNumForm::Named(StarPieceNumSerde {
+                world_1,
+                world_2,
+                world_3,
+                world_4,
+                world_5,
+                world_6,
+                world_s,
+                unknown,
+            }),
+            coin_galaxy_name: value
+                .coin_galaxy_name
+                .iter()
+                .map(|h| (h.into_raw() != 0).then_some(GalaxyNameSerde(*h)))
+                .collect(),
+            switch_data: value.switch_data,
+        }
+    }
+}
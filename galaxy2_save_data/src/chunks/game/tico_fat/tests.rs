@@ -0,0 +1,175 @@
+use binrw::{BinRead, BinWrite, io::Cursor};
+use galaxy_save_core::bin::BinaryDataChunk;
+
+use super::*;
+use crate::{catalog::Galaxy, chunks::game::GameDataChunk};
+
+/// Returns a data block of Hungry Luma state in the given byte order.
+fn chunk_bytes(endian: Endian, offset: u32, switch_data: &[u8]) -> Vec<u8> {
+    let to_bytes = |value: u32| match endian {
+        Endian::Big => value.to_be_bytes(),
+        Endian::Little => value.to_le_bytes(),
+    };
+    let hash = HashCode::from("SaveDataStorageTicoFat")
+        .into_raw()
+        .wrapping_add(offset);
+    let data_size = 12 + SaveDataStorageTicoFat::DATA_SIZE + switch_data.len();
+
+    let mut bytes = Vec::new();
+    bytes.extend_from_slice(&to_bytes(0x53544631));
+    bytes.extend_from_slice(&to_bytes(hash));
+    bytes.extend_from_slice(&to_bytes(data_size as u32));
+    bytes.extend((0..SaveDataStorageTicoFat::DATA_SIZE).map(|i| i as u8));
+    bytes.extend_from_slice(switch_data);
+
+    bytes
+}
+
+/// Reads a data block and writes it back in the same byte order.
+fn round_trip(bytes: &[u8], endian: Endian) -> (SaveDataStorageTicoFat, Vec<u8>) {
+    let chunk = GameDataChunk::read_options(&mut Cursor::new(bytes), endian, ()).unwrap();
+
+    let mut writer = Cursor::new(Vec::new());
+    chunk.write_options(&mut writer, endian, ()).unwrap();
+
+    let GameDataChunk::TicoFat(chunk) = chunk else {
+        panic!("expected Hungry Luma state, found {chunk:?}");
+    };
+
+    (chunk.inner, writer.into_inner())
+}
+
+#[test]
+fn test_round_trip_wii() {
+    let bytes = chunk_bytes(Endian::Big, 0x120, &[]);
+    let (storage, written) = round_trip(&bytes, Endian::Big);
+
+    assert!(!storage.is_switch_layout());
+    assert_eq!(written, bytes);
+}
+
+#[test]
+fn test_round_trip_switch() {
+    let bytes = chunk_bytes(Endian::Little, 0x1E0, &[1, 2, 3, 4, 5, 6, 7, 8]);
+    let (storage, written) = round_trip(&bytes, Endian::Little);
+
+    assert!(storage.is_switch_layout());
+    assert_eq!(storage.switch_data, [1, 2, 3, 4, 5, 6, 7, 8]);
+    assert_eq!(written, bytes);
+}
+
+#[test]
+fn test_round_trip_wii_layout_little_endian() {
+    let bytes = chunk_bytes(Endian::Little, 0x120, &[]);
+    let (storage, written) = round_trip(&bytes, Endian::Little);
+
+    assert!(!storage.is_switch_layout());
+    assert_eq!(written, bytes);
+}
+
+#[test]
+fn test_read_switch_layout_with_wii_hash() {
+    let bytes = chunk_bytes(Endian::Little, 0x120, &[1, 2, 3, 4]);
+    let err = BinaryDataChunk::<SaveDataStorageTicoFat>::read_le(&mut Cursor::new(&bytes[4..]))
+        .unwrap_err();
+
+    assert!(matches!(err.root_cause(), binrw::Error::AssertFail { .. }));
+}
+
+#[test]
+fn test_coin_feed_capacity() {
+    let mut tico_fat = SaveDataStorageTicoFat::default();
+    let galaxies = &Galaxy::ALL[..=SaveDataStorageTicoFat::COIN_GALAXY_NAME_NUM];
+
+    for galaxy in &galaxies[..SaveDataStorageTicoFat::COIN_GALAXY_NAME_NUM] {
+        tico_fat.on_coin_feed(galaxy.hash_code()).unwrap();
+    }
+    tico_fat.on_coin_feed(galaxies[0].hash_code()).unwrap();
+    assert!(
+        tico_fat
+            .on_coin_feed(galaxies[SaveDataStorageTicoFat::COIN_GALAXY_NAME_NUM].hash_code())
+            .is_err()
+    );
+
+    assert!(tico_fat.off_coin_feed(galaxies[0].hash_code()));
+    assert!(!tico_fat.is_coin_feed(galaxies[0].hash_code()));
+    assert_eq!(
+        tico_fat.coin_galaxy_names().count(),
+        SaveDataStorageTicoFat::COIN_GALAXY_NAME_NUM - 1
+    );
+}
+
+#[cfg(feature = "serde")]
+#[test]
+fn test_serde_round_trip() {
+    let mut tico_fat = SaveDataStorageTicoFat::default();
+    let luma = HungryLuma::new(World::Two, 3).unwrap();
+    *tico_fat.star_piece_num_mut(luma) = 40;
+    tico_fat.on_coin_feed(Galaxy::ALL[0].hash_code()).unwrap();
+
+    let json = serde_json::to_value(&tico_fat).unwrap();
+    let tico_fat: SaveDataStorageTicoFat = serde_json::from_value(json).unwrap();
+
+    assert_eq!(tico_fat.star_piece_num(luma), 40);
+    assert!(tico_fat.is_coin_feed(Galaxy::ALL[0].hash_code()));
+    assert!(!tico_fat.is_switch_layout());
+}
+
+#[cfg(feature = "serde")]
+#[test]
+fn test_serde_exceeds_capacity() {
+    let mut json = serde_json::to_value(SaveDataStorageTicoFat::default()).unwrap();
+    json["coin_galaxy_name"] = Galaxy::ALL[..=SaveDataStorageTicoFat::COIN_GALAXY_NAME_NUM]
+        .iter()
+        .map(|galaxy| galaxy.label())
+        .collect();
+
+    let error = serde_json::from_value::<SaveDataStorageTicoFat>(json).unwrap_err();
+    assert_eq!(error.to_string(), CoinGalaxyCapacityError.to_string());
+}
+
+#[cfg(feature = "serde")]
+#[test]
+fn test_serde_keeps_slots() {
+    let mut tico_fat = SaveDataStorageTicoFat::default();
+    tico_fat.coin_galaxy_name[2] = Galaxy::ALL[0].hash_code().into();
+    tico_fat.coin_galaxy_name[5] = Galaxy::ALL[0].hash_code().into();
+
+    let json = serde_json::to_value(&tico_fat).unwrap();
+    let names = json["coin_galaxy_name"].as_array().unwrap();
+
+    assert_eq!(names.len(), SaveDataStorageTicoFat::COIN_GALAXY_NAME_NUM);
+    assert!(names[0].is_null());
+    assert_eq!(names[2], Galaxy::ALL[0].label());
+
+    let read: SaveDataStorageTicoFat = serde_json::from_value(json).unwrap();
+
+    assert_eq!(read.coin_galaxy_name, tico_fat.coin_galaxy_name);
+}
+
+#[cfg(feature = "serde")]
+#[test]
+fn test_serde_earlier_form() {
+    let mut star_piece_num = [[0u16; SaveDataStorageTicoFat::PARTS_NUM]; 8];
+    star_piece_num[1][3] = 40;
+
+    let mut coin_galaxy_name = vec!["0x0"; SaveDataStorageTicoFat::COIN_GALAXY_NAME_NUM];
+    coin_galaxy_name[1] = Galaxy::ALL[0].label();
+
+    let json = serde_json::json!({
+        "star_piece_num": star_piece_num,
+        "coin_galaxy_name": coin_galaxy_name,
+    });
+    let tico_fat: SaveDataStorageTicoFat = serde_json::from_value(json).unwrap();
+
+    assert_eq!(
+        tico_fat.star_piece_num(HungryLuma::new(World::Two, 3).unwrap()),
+        40
+    );
+    assert_eq!(tico_fat.coin_galaxy_name[0].into_raw(), 0);
+    assert_eq!(
+        tico_fat.coin_galaxy_name[1],
+        HashCode16::from(Galaxy::ALL[0].hash_code())
+    );
+    assert!(!tico_fat.is_switch_layout());
+}
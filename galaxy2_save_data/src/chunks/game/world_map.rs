@@ -6,6 +6,8 @@ use galaxy_save_core::{array::BitArray8, bin::Chunk, hash::HashCode};
 #[cfg(feature = "serde")]
 use serde::{Deserialize, Serialize};
 
+use crate::catalog::{Galaxy, World};
+
 /// A container for World Map state.
 #[binrw]
 #[br(import(_data_size: usize))]
@@ -25,6 +27,35 @@ impl SaveDataStorageWorldMap {
 
     /// The maximum number of worlds with data that can be stored.
     pub(super) const WORLD_CAPACITY: usize = Self::WORLD_NUM.next_power_of_two();
+
+    /// Returns the world currently being navigated, if valid.
+    pub fn world(&self) -> Option<World> {
+        World::ALL
+            .iter()
+            .copied()
+            .find(|w| w.world_no() == self.world_no as usize)
+    }
+
+    /// Updates the world currently being navigated.
+    pub fn set_world(&mut self, world: World) {
+        self.world_no = world.world_no() as u8;
+    }
+
+    /// Determines if the Star Barrier was passed.
+    pub fn is_passed(&self, barrier: StarBarrier) -> bool {
+        self.star_check_point_flag[barrier.world().world_no() - 1].test(barrier.index())
+    }
+
+    /// Updates the passage status of the Star Barrier.
+    pub fn set_passed(&mut self, barrier: StarBarrier, is_passed: bool) {
+        let flag = &mut self.star_check_point_flag[barrier.world().world_no() - 1];
+
+        if is_passed {
+            flag.set(barrier.index());
+        } else {
+            flag.clear(barrier.index());
+        }
+    }
 }
 
 impl Default for SaveDataStorageWorldMap {
@@ -45,3 +76,72 @@ impl Chunk for SaveDataStorageWorldMap {
         HashCode::from_raw(hash)
     }
 }
+
+/// A Star Barrier of the World Map, guarding the final galaxy of a world.
+///
+/// The number of Stars required to pass each Star Barrier is not catalogued,
+/// as the World Map's placement data is undocumented.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub enum StarBarrier {
+    /// The Star Barrier guarding Bowser Jr.'s Fiery Flotilla.
+    FieryFlotilla,
+
+    /// The Star Barrier guarding Bowser's Lava Lair.
+    LavaLair,
+
+    /// The Star Barrier guarding Bowser Jr.'s Fearsome Fleet.
+    FearsomeFleet,
+
+    /// The Star Barrier guarding Bowser's Gravity Gauntlet.
+    GravityGauntlet,
+
+    /// The Star Barrier guarding Bowser Jr.'s Boom Bunker.
+    BoomBunker,
+
+    /// The Star Barrier guarding Bowser's Galaxy Generator.
+    GalaxyGenerator,
+}
+
+impl StarBarrier {
+    /// Every Star Barrier, in the order of the World Map.
+    pub const ALL: &[Self] = &[
+        Self::FieryFlotilla,
+        Self::LavaLair,
+        Self::FearsomeFleet,
+        Self::GravityGauntlet,
+        Self::BoomBunker,
+        Self::GalaxyGenerator,
+    ];
+
+    /// Returns the world of the Star Barrier.
+    pub const fn world(self) -> World {
+        match self {
+            Self::FieryFlotilla => World::One,
+            Self::LavaLair => World::Two,
+            Self::FearsomeFleet => World::Three,
+            Self::GravityGauntlet => World::Four,
+            Self::BoomBunker => World::Five,
+            Self::GalaxyGenerator => World::Six,
+        }
+    }
+
+    /// Returns the index of the passage flag within the flags of the world.
+    ///
+    /// Each world holds a single Star Barrier, so its passage status is assumed
+    /// to be the first flag of the world. The remaining flags are undocumented.
+    pub const fn index(self) -> u32 {
+        0
+    }
+
+    /// Returns the galaxy guarded by the Star Barrier.
+    pub const fn galaxy(self) -> Galaxy {
+        match self {
+            Self::FieryFlotilla => Galaxy::VsKoopaJrLv1Galaxy,
+            Self::LavaLair => Galaxy::VsKoopaLv1Galaxy,
+            Self::FearsomeFleet => Galaxy::KoopaJrLv2Galaxy,
+            Self::GravityGauntlet => Galaxy::VsKoopaGravityGalaxy,
+            Self::BoomBunker => Galaxy::VsKoopaJrLv3Galaxy,
+            Self::GalaxyGenerator => Galaxy::VsKoopaLv3Galaxy,
+        }
+    }
+}
@@ -0,0 +1,68 @@
+//! Analyses of the collectibles remaining in a user file.
+//!
+//! The number of missions is only known for stored galaxy states, so the
+//! Stars of a galaxy that was never visited are not listed individually.
+//! Comet Medals and Hungry Lumas are not listed, as the galaxies holding a
+//! Comet Medal and the number of Star Bits each Hungry Luma requires are
+//! undocumented.
+
+use crate::{catalog::Galaxy, chunks::game::SaveDataStorageGalaxy};
+
+#[cfg(test)]
+mod tests;
+
+/// The collectibles remaining in a user file, in the order of the catalog.
+#[derive(Debug, Clone, Default)]
+pub struct CompletionReport {
+    /// The collection of remaining collectibles.
+    pub missing: Vec<Missing>,
+}
+
+impl CompletionReport {
+    /// Lists every Star and galaxy visit absent from the galaxy states.
+    ///
+    /// A missing galaxy state counts as a galaxy that was never visited.
+    pub fn new(galaxy_storage: Option<&SaveDataStorageGalaxy>) -> Self {
+        let mut missing = Vec::new();
+
+        for galaxy in Galaxy::ALL.iter().copied() {
+            let Some(stage) = galaxy_storage.and_then(|s| s.get(galaxy)) else {
+                missing.push(Missing::Galaxy(galaxy));
+                continue;
+            };
+
+            if !stage.scenario.iter().any(|s| s.flag.already_visited()) {
+                missing.push(Missing::Galaxy(galaxy));
+            }
+
+            for (index, scenario) in stage.scenario.iter().enumerate() {
+                if !scenario.flag.power_star() {
+                    missing.push(Missing::PowerStar { galaxy, index });
+                }
+            }
+        }
+
+        Self { missing }
+    }
+
+    /// Returns `true` if no collectibles remain.
+    pub fn is_complete(&self) -> bool {
+        self.missing.is_empty()
+    }
+}
+
+/// A collectible remaining in a user file.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Missing {
+    /// A Star that was not collected.
+    PowerStar {
+        /// The galaxy of the mission.
+        galaxy: Galaxy,
+
+        /// The index of the mission state.
+        index: usize,
+    },
+
+    /// A galaxy where no mission was ever selected.
+    Galaxy(Galaxy),
+}
@@ -0,0 +1,55 @@
+use super::*;
+
+#[test]
+fn test_complete() {
+    let mut galaxy_storage = SaveDataStorageGalaxy::default();
+
+    for galaxy in Galaxy::ALL.iter().copied() {
+        for scenario in &mut galaxy_storage.insert(galaxy, 3).scenario {
+            scenario.flag.set_power_star(true);
+            scenario.flag.set_already_visited(true);
+        }
+    }
+
+    let report = CompletionReport::new(Some(&galaxy_storage));
+
+    assert!(report.is_complete(), "{:?}", report.missing);
+}
+
+#[test]
+fn test_incomplete() {
+    let mut galaxy_storage = SaveDataStorageGalaxy::default();
+    let stage = galaxy_storage.insert(Galaxy::IslandFleetGalaxy, 2);
+
+    stage.scenario[0].flag.set_power_star(true);
+    stage.scenario[0].flag.set_already_visited(true);
+
+    let report = CompletionReport::new(Some(&galaxy_storage));
+
+    assert_eq!(
+        report.missing[..2],
+        [
+            Missing::PowerStar {
+                galaxy: Galaxy::IslandFleetGalaxy,
+                index: 1,
+            },
+            Missing::Galaxy(Galaxy::YosshiHomeGalaxy),
+        ]
+    );
+}
+
+#[test]
+fn test_empty() {
+    let galaxy_storage = SaveDataStorageGalaxy::default();
+    let expected = Galaxy::ALL
+        .iter()
+        .copied()
+        .map(Missing::Galaxy)
+        .collect::<Vec<_>>();
+
+    assert_eq!(
+        CompletionReport::new(Some(&galaxy_storage)).missing,
+        expected
+    );
+    assert_eq!(CompletionReport::new(None).missing, expected);
+}
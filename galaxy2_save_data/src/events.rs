@@ -0,0 +1,271 @@
+//! Catalogs of known key-value pairs, mirroring the documentation in `docs/galaxy2`.
+
+use std::borrow::Cow;
+
+use galaxy_save_core::hash::{Encoding, HashCode, HashKey};
+
+#[cfg(test)]
+mod tests;
+
+/// Defines an enumeration of known keys alongside their labels, descriptions and default values.
+macro_rules! catalog {
+    (
+        $(#[$meta:meta])*
+        $vis:vis enum $name:ident: $ty:ty {
+            $(
+                #[doc = $doc:literal]
+                $variant:ident = ($label:literal, $default:expr),
+            )*
+        }
+    ) => {
+        $(#[$meta])*
+        #[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+        $vis enum $name {
+            $(
+                #[doc = $doc]
+                $variant,
+            )*
+        }
+
+        impl $name {
+            /// Every known key, in the order of the documentation.
+            pub const ALL: &[Self] = &[$(Self::$variant,)*];
+
+            /// Returns the original label of the key.
+            pub const fn label(self) -> &'static str {
+                match self {
+                    $(Self::$variant => $label,)*
+                }
+            }
+
+            /// Returns the description of the key.
+            pub const fn description(self) -> &'static str {
+                match self {
+                    $(Self::$variant => $doc.trim_ascii(),)*
+                }
+            }
+
+            /// Returns the value assigned to the key by default.
+            pub const fn default_value(self) -> $ty {
+                match self {
+                    $(Self::$variant => $default,)*
+                }
+            }
+
+            /// Returns the hash of the label converted to the character encoding.
+            pub fn hash_code(self, encoding: Encoding) -> HashCode {
+                encoding
+                    .hash(self.label())
+                    .expect("label should be representable in every character encoding")
+            }
+        }
+
+        impl From<$name> for HashKey<'_> {
+            fn from(key: $name) -> Self {
+                Self::Label(Cow::Borrowed(key.label()))
+            }
+        }
+    };
+}
+
+catalog! {
+    /// A known key of a `GameEventFlag`.
+    pub enum Flag: bool {
+        /// Determines if the player was informed about collecting a Bee Mushroom for the first time.
+        BeeMarioFirstTransform = ("ハチマリオ初変身", false),
+        /// Determines if the player was informed about collecting a Boo Mushroom for the first time.
+        TeresaMarioFirstTransform = ("テレサマリオ初変身", false),
+        /// Determines if the player was informed about collecting a Spring Mushroom for the first time.
+        HopperMarioFirstTransform = ("ホッパーマリオ初変身", false),
+        /// Determines if the player was informed about collecting a Fire Flower for the first time.
+        FireMarioFirstTransform = ("ファイアマリオ初変身", false),
+        /// Determines if the player was informed about collecting an Ice Flower for the first time.
+        IceMarioFirstTransform = ("アイスマリオ初変身", false),
+        /// Determines if the player was informed about collecting a Rainbow Star for the first time.
+        InvincibleMarioFirstTransform = ("無敵マリオ初変身", false),
+        /// Determines if the player was informed about collecting a Rock Mushroom for the first time.
+        GoroRockMarioFirstTransform = ("ゴロ岩マリオ初変身", false),
+        /// Determines if the player was informed about collecting a Cloud Flower for the first time.
+        CloudMarioFirstTransform = ("雲マリオ初変身", false),
+        /// Determines if the player was informed about collecting a Spin Drill for the first time.
+        DrillFirstGet = ("ドリル初ゲット", false),
+        /// Determines if the player was informed about collecting a Life Mushroom for the first time.
+        LifeUpMushroomExplanation = ("ライフアップキノコ解説", false),
+        /// Determines if the player was informed about collecting a Life Mushroom for the first time.
+        OneUpMushroomExplanation = ("１ＵＰキノコ解説", false),
+        /// Determines if the player was informed about rescuing Yoshi in the first mission of the Yoshi Star Galaxy for the first time.
+        YoshiEncounter = ("ヨッシー出会い", false),
+        /// Determines if the player was informed about collecting a Comet Medal for the first time.
+        CometMedalExplanation = ("コメットメダル解説", false),
+        /// Determines if the player was notified about Co-Star Mode when P2 joins for the first time.
+        TwoPSupportExplanation = ("２Ｐサポート解説", false),
+        /// Determines if the player was lectured about riding the Star Ball.
+        TamakoroTutorial = ("タマコロチュートリアル", false),
+        /// Determines if the player was lectured about gliding with Fluzzard.
+        GlibirdTutorial = ("グライバードチュートリアル", false),
+        /// Determines if World 1 was introduced to the player.
+        World1FirstPlay = ("ワールド1初プレイ", false),
+        /// Determines if World 2 was introduced to the player.
+        World2FirstPlay = ("ワールド2初プレイ", false),
+        /// Determines if World 3 was introduced to the player.
+        World3FirstPlay = ("ワールド3初プレイ", false),
+        /// Determines if World 4 was introduced to the player.
+        World4FirstPlay = ("ワールド4初プレイ", false),
+        /// Determines if World 5 was introduced to the player.
+        World5FirstPlay = ("ワールド5初プレイ", false),
+        /// Determines if World 6 was introduced to the player.
+        World6FirstPlay = ("ワールド6初プレイ", false),
+        /// Determines if World S was introduced to the player.
+        World7FirstPlay = ("ワールド7初プレイ", false),
+        /// Determines if the player was suggested to visit previous worlds for Power Stars due to having collected them all from World 2—excluding those earned from Prankster Comets—but is lacking enough to open a Star Barrier.
+        World2GuidanceToOtherWorlds = ("ワールド2他のワールドへ誘導", false),
+        /// Determines if the player was suggested to visit previous worlds for Power Stars due to having collected them all from World 3—excluding those earned from Prankster Comets—but is lacking enough to open a Star Barrier.
+        World3GuidanceToOtherWorlds = ("ワールド3他のワールドへ誘導", false),
+        /// Determines if the player was suggested to visit previous worlds for Power Stars due to having collected them all from World 4—excluding those earned from Prankster Comets—but is lacking enough to open a Star Barrier.
+        World4GuidanceToOtherWorlds = ("ワールド4他のワールドへ誘導", false),
+        /// Determines if the player was suggested to visit previous worlds for Power Stars due to having collected them all from World 5—excluding those earned from Prankster Comets—but is lacking enough to open a Star Barrier.
+        World5GuidanceToOtherWorlds = ("ワールド5他のワールドへ誘導", false),
+        /// Determines if the player was suggested to visit previous worlds for Power Stars due to having collected them all from World 6—excluding those earned from Prankster Comets—but is lacking enough to open a Star Barrier.
+        World6GuidanceToOtherWorlds = ("ワールド6他のワールドへ誘導", false),
+        /// Determines if the player was suggested to visit another galaxy outside of World 2 after accumulating a multiple of three game overs.
+        World2GuidanceDueToGameOver = ("ワールド2ゲームオーバーによる誘導", false),
+        /// Determines if the player was suggested to visit another galaxy outside of World 3 after accumulating a multiple of three game overs.
+        World3GuidanceDueToGameOver = ("ワールド3ゲームオーバーによる誘導", false),
+        /// Determines if the player was suggested to visit another galaxy outside of World 4 after accumulating a multiple of three game overs.
+        World4GuidanceDueToGameOver = ("ワールド4ゲームオーバーによる誘導", false),
+        /// Determines if the player was suggested to visit another galaxy outside of World 5 after accumulating a multiple of three game overs.
+        World5GuidanceDueToGameOver = ("ワールド5ゲームオーバーによる誘導", false),
+        /// Determines if the player was suggested to visit another galaxy outside of World 6 after accumulating a multiple of three game overs.
+        World6GuidanceDueToGameOver = ("ワールド6ゲームオーバーによる誘導", false),
+        /// Determines if the Grand World Map was introduced to the player.
+        GrandGalaxyMapFirstPlay = ("グランドギャラクシーマップ初プレイ", false),
+        /// Determines if the sequence with the Green Stars following the player's completion of the 120-Star ending has played.
+        GreenStarAppearanceDemo = ("グリーンスター出現デモ", false),
+        /// Determines if the sequence upon first approach to Bowser's Galaxy Generator has played.
+        DemoBeforeFinalKoopaBattle = ("クッパ最終戦直前デモ", false),
+        /// Determines if the third mission of the Boulder Bowl Galaxy is available.
+        IsOpenScenarioGoroRockGalaxy3 = ("IsOpenScenarioGoroRockGalaxy3", false),
+        /// Determines if the second mission of the Wild Glide Galaxy is available.
+        IsOpenScenarioJungleGliderGalaxy2 = ("IsOpenScenarioJungleGliderGalaxy2", false),
+        /// Determines if the third mission of the Space Storm Galaxy is available.
+        IsOpenScenarioThunderFleetGalaxy3 = ("IsOpenScenarioThunderFleetGalaxy3", false),
+        /// Determines if the second mission of the Fleet Glide Galaxy is available.
+        IsOpenScenarioChallengeGliderGalaxy2 = ("IsOpenScenarioChallengeGliderGalaxy2", false),
+        /// Determines if the second mission of the Honeyhop Galaxy is available.
+        IsOpenScenarioHoneyBeeVillageGalaxy2 = ("IsOpenScenarioHoneyBeeVillageGalaxy2", false),
+        /// Determines if the second mission of the Slimy Spring Galaxy is available.
+        IsOpenScenarioUnderGroundDangeonGalaxy2 = ("IsOpenScenarioUnderGroundDangeonGalaxy2", false),
+        /// Determines if the second mission of the Fluffy Bluff Galaxy is available.
+        IsOpenScenarioMokumokuValleyGalaxy2 = ("IsOpenScenarioMokumokuValleyGalaxy2", false),
+        /// Determines if the opening was initialized.
+        OpeningExecution = ("オープニング実行", false),
+        /// Determines if the player was informed about a Prankster Comet entering a galaxy's orbit for the first time.
+        CometExplanation = ("コメット解説", false),
+        /// Determines if the banktoad was talked to for the first time.
+        BankerKinopioFirstTime = ("銀行屋キノピオ初回", false),
+        /// Determines if Luigi can appear on standby in supported galaxies.
+        IntrusiveLuigiAppearanceStart = ("でしゃばりルイージ出現開始", false),
+        /// Determines if the ending resulting from partially completing the game was initialized.
+        NormalEndingExecution = ("ノーマルエンディング実行", false),
+        /// Determines if the sequence with Lubba following the player's partial completion of the game has played.
+        AfterNormalEndingDemo = ("ノーマルエンディング後デモ", false),
+        /// Determines if the ending resulting from collecting 120 Stars was initialized.
+        OneHundredTwentyStarEndingExecution = ("スター120個エンディング実行", false),
+        /// Determines if the sequence with Lubba following the player's completion of the 120-Star ending has played.
+        After120StarEndingDemo = ("スター120個エンディング後デモ", false),
+        /// Determines if the Grandmaster Galaxy is available.
+        FinalGalaxyAppearance = ("最終ギャラクシー出現", false),
+        /// Determines if the message following the player's partial completion of the game was sent to the Wii Message Board.
+        NormalEndingMailSent = ("ノーマルエンディングメール送信", false),
+        /// Determines if the message following the player's full completion of the game was sent to the Wii Message Board.
+        CompleteMailSent = ("コンプリートメール送信", false),
+        /// Determines if 9,999 Star Bits were collected.
+        StarPieceCounterStop = ("スターピースカウンターストップ", false),
+        /// Determines if 9,999 coins were collected.
+        CoinCounterStop = ("コインカウンターストップ", false),
+        /// Determines if Luigi was played as.
+        LuigiPlayed = ("ルイージプレイ済", false),
+        /// Determines if Luigi can appear on standby in a mission he has not appeared in before.
+        IntrusiveLuigiAppearing = ("でしゃばりルイージ出現中", false),
+        /// Determines if the player got a game over, then saved and quit when prompted.
+        GameOverAndThenExit = ("ゲームオーバーで終了", false),
+    }
+}
+
+catalog! {
+    /// A known key of a `GameEventValue`.
+    pub enum Value: u16 {
+        /// Represents the most significant bytes for the best race time in the Wild Glide Galaxy, in frames.
+        GliderJungleHi = ("グライダー[ジャングル]/hi", 0),
+        /// Represents the least significant bytes for the best race time in the Wild Glide Galaxy, in frames.
+        GliderJungleLo = ("グライダー[ジャングル]/lo", 0),
+        /// Represents the most significant bytes for the best race time in the Fleet Glide Galaxy, in frames.
+        GliderChallengeHi = ("グライダー[チャレンジ]/hi", 0),
+        /// Represents the least significant bytes for the best race time in the Fleet Glide Galaxy, in frames.
+        GliderChallengeLo = ("グライダー[チャレンジ]/lo", 0),
+        /// Represents the least significant bytes for the high score in the second mission of the Fluffy Bluff Galaxy.
+        BestScoreMokumokuValleyGalaxyLo = ("ベストスコア[MokumokuValleyGalaxy]/lo", 0),
+        /// Represents the most significant bytes for the high score in the second mission of the Fluffy Bluff Galaxy.
+        BestScoreMokumokuValleyGalaxyHi = ("ベストスコア[MokumokuValleyGalaxy]/hi", 0),
+        /// Represents the least significant bytes for the high score in the second mission of the Honeyhop Galaxy.
+        BestScoreHoneyBeeVillageGalaxyLo = ("ベストスコア[HoneyBeeVillageGalaxy]/lo", 0),
+        /// Represents the most significant bytes for the high score in the second mission of the Honeyhop Galaxy.
+        BestScoreHoneyBeeVillageGalaxyHi = ("ベストスコア[HoneyBeeVillageGalaxy]/hi", 0),
+        /// Represents the least significant bytes for the high score in the second mission of the Slimy Spring Galaxy.
+        BestScoreUnderGroundDangeonGalaxyLo = ("ベストスコア[UnderGroundDangeonGalaxy]/lo", 0),
+        /// Represents the most significant bytes for the high score in the second mission of the Slimy Spring Galaxy.
+        BestScoreUnderGroundDangeonGalaxyHi = ("ベストスコア[UnderGroundDangeonGalaxy]/hi", 0),
+        /// Represents the least significant bytes for the high score in the third mission of the Melty Monster Galaxy.
+        BestScoreTwisterTowerGalaxyLo = ("ベストスコア[TwisterTowerGalaxy]/lo", 0),
+        /// Represents the most significant bytes for the high score in the third mission of the Melty Monster Galaxy.
+        BestScoreTwisterTowerGalaxyHi = ("ベストスコア[TwisterTowerGalaxy]/hi", 0),
+        /// Represents the least significant bytes for the high score in the third mission of the Shiverburn Galaxy.
+        BestScoreKachikochiLavaGalaxyLo = ("ベストスコア[KachikochiLavaGalaxy]/lo", 0),
+        /// Represents the most significant bytes for the high score in the third mission of the Shiverburn Galaxy.
+        BestScoreKachikochiLavaGalaxyHi = ("ベストスコア[KachikochiLavaGalaxy]/hi", 0),
+        /// Represents the least significant bytes for the high score in the third mission of the Freezy Flake Galaxy.
+        BestScoreWhiteSnowGalaxyLo = ("ベストスコア[WhiteSnowGalaxy]/lo", 0),
+        /// Represents the most significant bytes for the high score in the third mission of the Freezy Flake Galaxy.
+        BestScoreWhiteSnowGalaxyHi = ("ベストスコア[WhiteSnowGalaxy]/hi", 0),
+        /// Represents the least significant bytes for a bit field determining if a letter informing the player about a conditionally unlocked mission was received by the mailtoad.
+        PostmanTaskLetterReadFlag0 = ("郵便屋[タスク手紙既読フラグ]/0", 0),
+        /// Represents the most significant bytes for a bit field determining if a letter informing the player about a conditionally unlocked mission was received by the mailtoad.
+        PostmanTaskLetterReadFlag1 = ("郵便屋[タスク手紙既読フラグ]/1", 0),
+        /// Represents the least significant bytes for a bit field determining if a letter gifting the player was received by the mailtoad.
+        PostmanImportantLetterReadFlag0 = ("郵便屋[重要手紙既読フラグ]/0", 0),
+        /// Represents the most significant bytes for a bit field determining if a letter gifting the player was received by the mailtoad.
+        PostmanImportantLetterReadFlag1 = ("郵便屋[重要手紙既読フラグ]/1", 0),
+        /// Represents the bit index of the most recent letter received by the mailtoad informing the player about a conditionally unlocked mission.
+        PostmanLastReadTaskLetterIndex = ("郵便屋[最後に読んだタスク手紙インデックス]", 0),
+        /// Represents the number of lives lost as of reading Princess Peach's letter.
+        PostmanCumulativeNumberOfDeathsWhenReadingPeachsLetter = ("郵便屋[ピーチ手紙を読んだ時の累積死亡回数]", 0),
+        /// Represents the least significant bytes for a bit field determining if a message from an actor was read by the player.
+        MessageAlreadyReadFlag0 = ("メッセージ既読フラグ/0", 0),
+        /// Represents the most significant bytes for a bit field determining if a message from an actor was read by the player.
+        MessageAlreadyReadFlag1 = ("メッセージ既読フラグ/1", 0),
+        /// Represents the number of lives lost.
+        CumulativeNumberOfDeaths = ("累積死亡回数", 0),
+        /// Represents the number of game overs.
+        CumulativeNumberOfGameOvers = ("累積ゲームオーバー回数", 0),
+        /// Represents the least significant bytes for the amount of time spent actively playing the game, in frames.
+        CumulativePlayTimeLo = ("累積プレイ時間/lo", 0),
+        /// Represents the most significant bytes for the amount of time spent actively playing the game, in frames.
+        CumulativePlayTimeHi = ("累積プレイ時間/hi", 0),
+        /// Represents the number of missions cleared and lives lost to allow Luigi to appear on standby.
+        IntrusiveLuigiAppearanceCounter = ("でしゃばりルイージ出現カウンタ", 0),
+        /// Represents the number of Star Bits earned by the Banktoad to be added to the number of banked Star Bits.
+        BankerKinopioInterest = ("銀行屋キノピオ[利子]", 0),
+        /// TBD
+        FacePlanetEventNumber0 = ("顔惑星イベント番号/0", 0),
+        /// TBD
+        FacePlanetEventNumber1 = ("顔惑星イベント番号/1", 0),
+        /// TBD
+        FacePlanetEventNumber2 = ("顔惑星イベント番号/2", 0),
+        /// TBD
+        FacePlanetEventNumber3 = ("顔惑星イベント番号/3", 0),
+        /// TBD
+        FacePlanetEventGrandStarNumber = ("顔惑星イベントグランドスター番号", 0),
+        /// TBD
+        NumberOfStagesClearedAfterACertainNumberOfDeaths = ("一定数死亡後のステージクリア回数", 0),
+    }
+}
@@ -0,0 +1,41 @@
+use galaxy_save_core::{event::GameEventFlag, hash::HashCodeMap, save::Platform};
+
+use super::*;
+use crate::catalog::Galaxy;
+
+/// Reads the labels file shipped with the crate under the character encoding of the platform.
+fn read_labels(platform: Platform) -> HashCodeMap {
+    let mut map = HashCodeMap::default();
+
+    map.set_encoding(Some(platform.encoding()));
+    map.read(concat!(env!("CARGO_MANIFEST_DIR"), "/resources/labels.txt"))
+        .unwrap();
+    map
+}
+
+#[test]
+fn test_labels_resolve_known_keys() {
+    for platform in [Platform::Wii, Platform::Switch] {
+        let map = read_labels(platform);
+        let resolve = |label: &str, width: u32| {
+            let hash = map.hash_of(label).unwrap();
+
+            map.label_of(hash, Some(width)).cloned()
+        };
+
+        for flag in Flag::ALL {
+            let label = resolve(flag.label(), GameEventFlag::KEY_WIDTH);
+            assert_eq!(label.as_deref(), Some(flag.label()), "{platform:?}");
+        }
+
+        for value in Value::ALL {
+            let label = resolve(value.label(), u16::BITS);
+            assert_eq!(label.as_deref(), Some(value.label()), "{platform:?}");
+        }
+
+        for galaxy in Galaxy::ALL {
+            let label = resolve(galaxy.label(), u16::BITS);
+            assert_eq!(label.as_deref(), Some(galaxy.label()), "{platform:?}");
+        }
+    }
+}
@@ -0,0 +1,260 @@
+//! Consistent progression of a user file through the worlds.
+
+use galaxy_save_core::{
+    bin::{CapacityError, ChunkHolder},
+    save::Platform,
+};
+use thiserror::Error;
+
+use crate::{
+    catalog::{Galaxy, World},
+    chunks::game::{GameDataChunk, galaxy::SaveDataStorageGalaxyState, world_map::StarBarrier},
+    events::Flag,
+};
+
+#[cfg(test)]
+mod tests;
+
+/// The event flags determining if each world was introduced to the player.
+const WORLD_FIRST_PLAY_FLAGS: [Flag; 7] = [
+    Flag::World1FirstPlay,
+    Flag::World2FirstPlay,
+    Flag::World3FirstPlay,
+    Flag::World4FirstPlay,
+    Flag::World5FirstPlay,
+    Flag::World6FirstPlay,
+    Flag::World7FirstPlay,
+];
+
+/// Moves the story to the start of the world.
+///
+/// The world is made the one currently being navigated and the introduction
+/// flag of each world is set up to the world and cleared after it. Every Star
+/// Barrier of a preceding world is passed, and the Star Barrier of the world and
+/// of each later world is not, leaving the other flags of each world as is.
+/// Every stored galaxy of a preceding world is opened, every
+/// stored galaxy of the world becomes available unless guarded by a Star
+/// Barrier that was not passed, and every stored galaxy of a later world is
+/// closed. Missing galaxy states are not added, as the number of missions of
+/// each galaxy is not catalogued. New introduction flags are hashed under the
+/// character encoding of the platform. Nothing is modified if the event flag,
+/// galaxy or World Map state is missing, or if the introduction flags do not
+/// fit within the data buffer.
+pub fn progress_to(
+    chunks: &mut [GameDataChunk],
+    world: World,
+    platform: Platform,
+) -> Result<(), ProgressError> {
+    let event_flag = chunks
+        .iter()
+        .find_map(|c| match c {
+            GameDataChunk::EventFlag(chunk) => Some(&chunk.inner),
+            _ => None,
+        })
+        .ok_or(MissingChunkError::EventFlag)?;
+    let new_flag_num = World::ALL
+        .iter()
+        .zip(WORLD_FIRST_PLAY_FLAGS)
+        .filter(|(other, flag)| **other <= world && !event_flag.contains(*flag))
+        .count();
+    let growth = event_flag.growth(new_flag_num);
+
+    if !chunks.iter().any(|c| matches!(c, GameDataChunk::Galaxy(_))) {
+        return Err(MissingChunkError::Galaxy.into());
+    }
+
+    if !chunks
+        .iter()
+        .any(|c| matches!(c, GameDataChunk::WorldMap(_)))
+    {
+        return Err(MissingChunkError::WorldMap.into());
+    }
+
+    GameDataChunk::check_capacity(chunks, growth)?;
+
+    let mut event_flag = None;
+    let mut galaxy_storage = None;
+    let mut world_map = None;
+
+    for chunk in chunks {
+        match chunk {
+            GameDataChunk::EventFlag(chunk) => event_flag = Some(&mut chunk.inner),
+            GameDataChunk::Galaxy(chunk) => galaxy_storage = Some(&mut chunk.inner),
+            GameDataChunk::WorldMap(chunk) => world_map = Some(&mut chunk.inner),
+            _ => {}
+        }
+    }
+
+    let event_flag = event_flag.ok_or(MissingChunkError::EventFlag)?;
+    let galaxy_storage = galaxy_storage.ok_or(MissingChunkError::Galaxy)?;
+    let world_map = world_map.ok_or(MissingChunkError::WorldMap)?;
+
+    for (other, flag) in World::ALL.iter().copied().zip(WORLD_FIRST_PLAY_FLAGS) {
+        if event_flag.contains(flag) {
+            event_flag.set_flag(flag, other <= world);
+        } else if other <= world {
+            event_flag.push(flag.hash_code(platform.encoding()), true);
+        }
+    }
+
+    for barrier in StarBarrier::ALL.iter().copied() {
+        world_map.set_passed(barrier, barrier.world() < world);
+    }
+
+    for galaxy in Galaxy::ALL.iter().copied() {
+        let Some(stage) = galaxy_storage.get_mut(galaxy) else {
+            continue;
+        };
+        let is_guarded = StarBarrier::ALL
+            .iter()
+            .any(|b| b.galaxy() == galaxy && !world_map.is_passed(*b));
+
+        if galaxy.world() > world || is_guarded {
+            stage.galaxy_state = SaveDataStorageGalaxyState::Closed;
+        } else if galaxy.world() < world {
+            stage.galaxy_state = SaveDataStorageGalaxyState::Opened;
+        } else if stage.galaxy_state == SaveDataStorageGalaxyState::Closed {
+            stage.galaxy_state = SaveDataStorageGalaxyState::New;
+        }
+    }
+
+    world_map.set_world(world);
+
+    Ok(())
+}
+
+/// Opens everything up to and including the world.
+///
+/// The story is moved to the start of the world as by [`progress_to`], then
+/// every stored galaxy of the world is opened and every Star Barrier up to the
+/// world is passed. Nothing is modified if [`progress_to`] fails.
+pub fn open_to(
+    chunks: &mut [GameDataChunk],
+    world: World,
+    platform: Platform,
+) -> Result<(), ProgressError> {
+    progress_to(chunks, world, platform)?;
+
+    for chunk in chunks {
+        match chunk {
+            GameDataChunk::Galaxy(chunk) => {
+                for galaxy in world.galaxies() {
+                    if let Some(stage) = chunk.inner.get_mut(galaxy) {
+                        stage.galaxy_state = SaveDataStorageGalaxyState::Opened;
+                    }
+                }
+            }
+            GameDataChunk::WorldMap(chunk) => {
+                for barrier in StarBarrier::ALL.iter().copied() {
+                    if barrier.world() <= world {
+                        chunk.inner.set_passed(barrier, true);
+                    }
+                }
+            }
+            _ => {}
+        }
+    }
+
+    Ok(())
+}
+
+/// Lists the inconsistencies between the World Map state and the galaxy states.
+///
+/// A galaxy is expected to be closed while the Star Barrier guarding it, or
+/// any Star Barrier of a preceding world, was not passed. The number of
+/// collected Stars is not checked, as the number of Stars required by each Star
+/// Barrier is not catalogued.
+pub fn check_world_map(chunks: &[GameDataChunk]) -> Result<Vec<Inconsistency>, MissingChunkError> {
+    let mut galaxy_storage = None;
+    let mut world_map = None;
+
+    for chunk in chunks {
+        match chunk {
+            GameDataChunk::Galaxy(chunk) => galaxy_storage = Some(&chunk.inner),
+            GameDataChunk::WorldMap(chunk) => world_map = Some(&chunk.inner),
+            _ => {}
+        }
+    }
+
+    let galaxy_storage = galaxy_storage.ok_or(MissingChunkError::Galaxy)?;
+    let world_map = world_map.ok_or(MissingChunkError::WorldMap)?;
+
+    let mut inconsistencies = Vec::new();
+
+    if world_map.world().is_none() {
+        inconsistencies.push(Inconsistency::WorldNo(world_map.world_no));
+    }
+
+    for barrier in StarBarrier::ALL.iter().copied() {
+        if world_map.is_passed(barrier) {
+            continue;
+        }
+
+        for galaxy in Galaxy::ALL.iter().copied() {
+            if galaxy != barrier.galaxy() && galaxy.world() <= barrier.world() {
+                continue;
+            }
+
+            let Some(stage) = galaxy_storage.get(galaxy) else {
+                continue;
+            };
+
+            if stage.galaxy_state != SaveDataStorageGalaxyState::Closed {
+                inconsistencies.push(Inconsistency::GalaxyBehindStarBarrier {
+                    barrier,
+                    galaxy,
+                    galaxy_state: stage.galaxy_state,
+                });
+            }
+        }
+    }
+
+    Ok(inconsistencies)
+}
+
+/// An inconsistency between the World Map state and the rest of a user file.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Inconsistency {
+    /// The world currently being navigated does not exist.
+    WorldNo(u8),
+
+    /// A galaxy behind a Star Barrier that was not passed is available.
+    GalaxyBehindStarBarrier {
+        /// The Star Barrier that was not passed.
+        barrier: StarBarrier,
+
+        /// The galaxy behind the Star Barrier.
+        galaxy: Galaxy,
+
+        /// The unit state of the galaxy on the World Map.
+        galaxy_state: SaveDataStorageGalaxyState,
+    },
+}
+
+/// An error returned from progressing a user file.
+#[derive(Debug, Error)]
+pub enum ProgressError {
+    /// A required data block is missing.
+    #[error(transparent)]
+    MissingChunk(#[from] MissingChunkError),
+
+    /// The event flags required by the progression do not fit within the data buffer.
+    #[error(transparent)]
+    Capacity(#[from] CapacityError),
+}
+
+/// An error returned from progressing a user file without a required data block.
+#[derive(Debug, Error)]
+pub enum MissingChunkError {
+    /// The data block dedicated to preserving event flags is missing.
+    #[error("the user file has no event flag state")]
+    EventFlag,
+
+    /// The data block dedicated to preserving galaxy state is missing.
+    #[error("the user file has no galaxy state")]
+    Galaxy,
+
+    /// The data block dedicated to preserving World Map state is missing.
+    #[error("the user file has no World Map state")]
+    WorldMap,
+}
@@ -0,0 +1,204 @@
+use binrw::{BinRead, io::Cursor};
+use galaxy_save_core::{array::BitArray8, bin::BinaryDataChunk, hash::HashCode};
+
+use super::*;
+use crate::chunks::game::{
+    SaveDataStorageEventFlag, SaveDataStorageGalaxy, SaveDataStorageWorldMap,
+};
+
+/// Returns the data blocks of a user file storing the galaxies.
+fn chunks(galaxies: &[Galaxy]) -> Vec<GameDataChunk> {
+    let event_flag = SaveDataStorageEventFlag::read_be_args(&mut Cursor::new([]), (0,)).unwrap();
+    let mut galaxy_storage = SaveDataStorageGalaxy::default();
+
+    for galaxy in galaxies {
+        galaxy_storage.insert(*galaxy, 1);
+    }
+
+    vec![
+        GameDataChunk::EventFlag(BinaryDataChunk { inner: event_flag }),
+        GameDataChunk::Galaxy(BinaryDataChunk {
+            inner: galaxy_storage,
+        }),
+        GameDataChunk::WorldMap(BinaryDataChunk {
+            inner: SaveDataStorageWorldMap::default(),
+        }),
+    ]
+}
+
+/// Returns the unit state of a stored galaxy.
+fn galaxy_state(chunks: &[GameDataChunk], galaxy: Galaxy) -> SaveDataStorageGalaxyState {
+    chunks
+        .iter()
+        .find_map(|c| match c {
+            GameDataChunk::Galaxy(chunk) => chunk.inner.get(galaxy),
+            _ => None,
+        })
+        .unwrap()
+        .galaxy_state
+}
+
+/// Returns the World Map state.
+fn world_map(chunks: &[GameDataChunk]) -> &SaveDataStorageWorldMap {
+    chunks
+        .iter()
+        .find_map(|c| match c {
+            GameDataChunk::WorldMap(chunk) => Some(&chunk.inner),
+            _ => None,
+        })
+        .unwrap()
+}
+
+#[test]
+fn test_open_to() {
+    let galaxies = [
+        Galaxy::IslandFleetGalaxy,
+        Galaxy::VsKoopaJrLv1Galaxy,
+        Galaxy::HomeCenterGalaxy,
+        Galaxy::VsKoopaLv1Galaxy,
+        Galaxy::BigTree2Galaxy,
+    ];
+    let mut chunks = chunks(&galaxies);
+
+    open_to(&mut chunks, World::Two, Platform::Wii).unwrap();
+
+    for galaxy in &galaxies[..4] {
+        assert_eq!(
+            galaxy_state(&chunks, *galaxy),
+            SaveDataStorageGalaxyState::Opened
+        );
+    }
+    assert_eq!(
+        galaxy_state(&chunks, Galaxy::BigTree2Galaxy),
+        SaveDataStorageGalaxyState::Closed
+    );
+
+    let world_map = world_map(&chunks);
+    assert_eq!(world_map.world(), Some(World::Two));
+    assert!(world_map.is_passed(StarBarrier::FieryFlotilla));
+    assert!(world_map.is_passed(StarBarrier::LavaLair));
+    assert!(!world_map.is_passed(StarBarrier::FearsomeFleet));
+
+    assert!(check_world_map(&chunks).unwrap().is_empty());
+}
+
+#[test]
+fn test_progress_to_resets_only_star_barriers() {
+    let mut chunks = chunks(&[]);
+
+    if let GameDataChunk::WorldMap(chunk) = &mut chunks[2] {
+        chunk.inner.star_check_point_flag = [BitArray8::from(0b1111_1110); 8];
+    }
+
+    open_to(&mut chunks, World::Three, Platform::Wii).unwrap();
+    progress_to(&mut chunks, World::Two, Platform::Wii).unwrap();
+
+    let world_map = world_map(&chunks);
+
+    assert!(world_map.is_passed(StarBarrier::FieryFlotilla));
+    assert!(!world_map.is_passed(StarBarrier::LavaLair));
+    assert!(!world_map.is_passed(StarBarrier::FearsomeFleet));
+
+    // The undocumented flags of each world are left as is.
+    for flag in world_map.star_check_point_flag {
+        for index in 1..u8::BITS {
+            assert!(flag.test(index));
+        }
+    }
+}
+
+#[test]
+fn test_open_to_missing_galaxies() {
+    let mut chunks = chunks(&[]);
+
+    open_to(&mut chunks, World::Three, Platform::Wii).unwrap();
+
+    let GameDataChunk::Galaxy(chunk) = &chunks[1] else {
+        panic!("expected galaxy state, found {:?}", chunks[1]);
+    };
+    assert_eq!(chunk.inner.iter().count(), 0);
+}
+
+#[test]
+fn test_check_world_map() {
+    let mut chunks = chunks(&[
+        Galaxy::IslandFleetGalaxy,
+        Galaxy::VsKoopaJrLv1Galaxy,
+        Galaxy::HomeCenterGalaxy,
+    ]);
+
+    progress_to(&mut chunks, World::One, Platform::Wii).unwrap();
+    assert_eq!(
+        galaxy_state(&chunks, Galaxy::VsKoopaJrLv1Galaxy),
+        SaveDataStorageGalaxyState::Closed
+    );
+    assert!(check_world_map(&chunks).unwrap().is_empty());
+
+    if let GameDataChunk::Galaxy(chunk) = &mut chunks[1] {
+        for galaxy in [Galaxy::VsKoopaJrLv1Galaxy, Galaxy::HomeCenterGalaxy] {
+            chunk.inner.get_mut(galaxy).unwrap().galaxy_state = SaveDataStorageGalaxyState::Opened;
+        }
+    }
+    if let GameDataChunk::WorldMap(chunk) = &mut chunks[2] {
+        chunk.inner.world_no = 0;
+    }
+
+    assert_eq!(
+        check_world_map(&chunks).unwrap(),
+        [
+            Inconsistency::WorldNo(0),
+            Inconsistency::GalaxyBehindStarBarrier {
+                barrier: StarBarrier::FieryFlotilla,
+                galaxy: Galaxy::VsKoopaJrLv1Galaxy,
+                galaxy_state: SaveDataStorageGalaxyState::Opened,
+            },
+            Inconsistency::GalaxyBehindStarBarrier {
+                barrier: StarBarrier::FieryFlotilla,
+                galaxy: Galaxy::HomeCenterGalaxy,
+                galaxy_state: SaveDataStorageGalaxyState::Opened,
+            },
+        ]
+    );
+}
+
+#[test]
+fn test_missing_chunk() {
+    let mut chunks = chunks(&[]);
+    chunks.pop();
+
+    assert!(matches!(
+        check_world_map(&chunks),
+        Err(MissingChunkError::WorldMap)
+    ));
+    assert!(matches!(
+        open_to(&mut chunks, World::One, Platform::Wii),
+        Err(ProgressError::MissingChunk(MissingChunkError::WorldMap))
+    ));
+}
+
+#[test]
+fn test_progress_to_exceeds_capacity() {
+    let mut chunks = chunks(&[Galaxy::IslandFleetGalaxy]);
+    let world_no = world_map(&chunks).world_no;
+    let mut raw = 0;
+
+    // Fills the data buffer with an even number of placeholder keys, so that
+    // no new key fits within the alignment padding.
+    while GameDataChunk::remaining_capacity(&chunks).unwrap() > 0 || raw % 2 != 0 {
+        if let GameDataChunk::EventFlag(chunk) = &mut chunks[0] {
+            chunk.inner.push(HashCode::from_raw(raw), false);
+        }
+
+        raw += 1;
+    }
+
+    assert!(matches!(
+        progress_to(&mut chunks, World::Two, Platform::Wii),
+        Err(ProgressError::Capacity(_))
+    ));
+    assert_eq!(world_map(&chunks).world_no, world_no);
+    assert_eq!(
+        galaxy_state(&chunks, Galaxy::IslandFleetGalaxy),
+        SaveDataStorageGalaxyState::default()
+    );
+}
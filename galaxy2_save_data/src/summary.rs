@@ -0,0 +1,113 @@
+//! Summaries of the collection progress of a user file.
+//!
+//! Green Stars are stored as mission states like any other Star, so they are
+//! included in the number of collected Stars rather than counted separately.
+
+use galaxy_save_core::hash::HashCode16;
+
+use crate::{
+    catalog::{Galaxy, World},
+    chunks::game::{
+        SaveDataStorageGalaxy,
+        galaxy::{SaveDataStorageGalaxyScenario, SaveDataStorageGalaxyStage},
+    },
+};
+
+#[cfg(test)]
+mod tests;
+
+/// A summary of the collection progress of a user file.
+#[derive(Debug, Clone, Default)]
+pub struct GameDataSummary {
+    /// The total number of collected Stars.
+    pub power_star_num: u32,
+
+    /// The total number of collected Bronze Stars.
+    pub bronze_star_num: u32,
+
+    /// The total number of collected Comet Medals.
+    pub tico_coin_num: u32,
+
+    /// The summary of each stored galaxy state.
+    pub galaxy: Vec<GalaxySummary>,
+}
+
+impl GameDataSummary {
+    /// Summarizes the galaxy states.
+    pub fn new(storage: &SaveDataStorageGalaxy) -> Self {
+        let galaxy = storage.iter().map(GalaxySummary::new).collect::<Vec<_>>();
+
+        Self {
+            power_star_num: galaxy.iter().map(|g| g.power_star_num).sum(),
+            bronze_star_num: galaxy.iter().map(|g| g.bronze_star_num).sum(),
+            tico_coin_num: galaxy.iter().filter(|g| g.tico_coin).count() as u32,
+            galaxy,
+        }
+    }
+
+    /// Returns the summary of the galaxy, if stored.
+    pub fn get(&self, galaxy: Galaxy) -> Option<&GalaxySummary> {
+        self.galaxy.iter().find(|g| g.galaxy == Some(galaxy))
+    }
+
+    /// Returns the number of collected Stars in the galaxies of the world.
+    pub fn world_power_star_num(&self, world: World) -> u32 {
+        self.world(world).map(|g| g.power_star_num).sum()
+    }
+
+    /// Returns the number of collected Comet Medals in the galaxies of the world.
+    pub fn world_tico_coin_num(&self, world: World) -> u32 {
+        self.world(world).filter(|g| g.tico_coin).count() as u32
+    }
+
+    /// Returns an iterator over the summaries of the galaxies of the world.
+    fn world(&self, world: World) -> impl Iterator<Item = &GalaxySummary> {
+        self.galaxy
+            .iter()
+            .filter(move |g| g.galaxy.map(Galaxy::world) == Some(world))
+    }
+}
+
+/// A summary of the collection progress of a galaxy.
+#[derive(Debug, Clone)]
+pub struct GalaxySummary {
+    /// The hashed internal name of the galaxy, truncated to the least significant 16 bits.
+    pub galaxy_name: HashCode16,
+
+    /// The catalog entry of the galaxy, if known.
+    pub galaxy: Option<Galaxy>,
+
+    /// The number of stored mission states.
+    pub scenario_num: usize,
+
+    /// The number of collected Stars.
+    pub power_star_num: u32,
+
+    /// The number of collected Bronze Stars.
+    pub bronze_star_num: u32,
+
+    /// Determines if the Comet Medal was collected.
+    pub tico_coin: bool,
+
+    /// The best clear time of each mission, in frames.
+    pub best_time: Vec<u32>,
+}
+
+impl GalaxySummary {
+    /// Summarizes the galaxy state.
+    pub fn new(stage: &SaveDataStorageGalaxyStage) -> Self {
+        let count = |f: fn(&SaveDataStorageGalaxyScenario) -> bool| {
+            stage.scenario.iter().filter(|s| f(s)).count() as u32
+        };
+
+        Self {
+            galaxy_name: stage.galaxy_name(),
+            galaxy: stage.galaxy(),
+            scenario_num: stage.scenario.len(),
+            power_star_num: count(|s| s.flag.power_star()),
+            bronze_star_num: count(|s| s.flag.bronze_star()),
+            tico_coin: stage.flag.tico_coin(),
+            best_time: stage.scenario.iter().map(|s| s.best_time).collect(),
+        }
+    }
+}
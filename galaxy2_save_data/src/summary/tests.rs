@@ -0,0 +1,27 @@
+use super::*;
+
+#[test]
+fn test_world_tallies() {
+    let mut storage = SaveDataStorageGalaxy::default();
+
+    let stage = storage.insert(Galaxy::IslandFleetGalaxy, 3);
+    stage.flag.set_tico_coin(true);
+    stage.scenario[0].flag.set_power_star(true);
+    stage.scenario[2].flag.set_power_star(true);
+
+    let stage = storage.insert(Galaxy::YosshiHomeGalaxy, 2);
+    stage.scenario[1].flag.set_power_star(true);
+
+    let stage = storage.insert(Galaxy::HomeCenterGalaxy, 1);
+    stage.flag.set_tico_coin(true);
+
+    let summary = GameDataSummary::new(&storage);
+
+    assert_eq!(summary.power_star_num, 3);
+    assert_eq!(summary.tico_coin_num, 2);
+    assert_eq!(summary.world_power_star_num(World::One), 3);
+    assert_eq!(summary.world_power_star_num(World::Two), 0);
+    assert_eq!(summary.world_tico_coin_num(World::One), 1);
+    assert_eq!(summary.world_tico_coin_num(World::Two), 1);
+    assert_eq!(summary.world_tico_coin_num(World::Three), 0);
+}
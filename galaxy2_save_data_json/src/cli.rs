@@ -1,4 +1,8 @@
-use clap::Parser;
+use clap::{
+    Parser,
+    builder::{PossibleValue, PossibleValuesParser, TypedValueParser},
+};
+use galaxy_save_core::save::Platform;
 
 /// Convert Super Mario Galaxy 2 save files to and from JSON.
 #[derive(Parser)]
@@ -18,7 +22,35 @@ pub struct Args {
     #[arg(short, long, requires("labels"))]
     pub strict: bool,
 
+    /// Include the English translation of annotated hash labels in JSON files.
+    #[arg(short, long, requires("labels"))]
+    pub translate: bool,
+
+    /// The source or target console.
+    #[arg(short, long, default_value = "wii", value_parser = platform_parser())]
+    pub platform: Platform,
+
+    /// The templates and dictionaries file path for recovering the labels of unknown hashes.
+    #[arg(short, long)]
+    pub recover: Option<String>,
+
+    /// Print the collectibles remaining in each user file instead of converting.
+    #[arg(short, long)]
+    pub completion: bool,
+
     /// Parse the save file even if the header is invalid.
     #[arg(short, long)]
     pub force: bool,
 }
+
+/// Returns the parser for the compatible consoles.
+fn platform_parser() -> impl TypedValueParser<Value = Platform> {
+    PossibleValuesParser::new([
+        PossibleValue::new("wii").help("Nintendo Wii"),
+        PossibleValue::new("switch").help("Nintendo Switch"),
+    ])
+    .map(|value| match value.as_str() {
+        "switch" => Platform::Switch,
+        _ => Platform::Wii,
+    })
+}
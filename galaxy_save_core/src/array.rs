@@ -123,6 +123,22 @@ impl BitArray8 {
 
         self.inner &= !mask;
     }
+
+    /// Returns the number of bits equal to `1`.
+    ///
+    /// # Examples
+    ///
+    /// Basic usage:
+    ///
+    /// ```
+    /// use galaxy_save_core::array::BitArray8;
+    ///
+    /// let a = BitArray8::from(0b01001011);
+    /// assert_eq!(a.count_ones(), 4);
+    /// ```
+    pub const fn count_ones(&self) -> u32 {
+        self.inner.count_ones()
+    }
 }
 
 impl From<u8> for BitArray8 {
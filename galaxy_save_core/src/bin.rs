@@ -7,7 +7,7 @@ mod chunk_holder;
 mod content;
 
 pub use chunk::{BinaryDataChunk, Chunk};
-pub use chunk_holder::{BinaryDataChunkHolder, ChunkHolder};
+pub use chunk_holder::{BinaryDataChunkHolder, BufferOverflowError, CapacityError, ChunkHolder};
 pub use content::{
     BinaryDataContentAttribute, BinaryDataContentHeaderSerializer, HeaderSerializer,
 };
@@ -37,7 +37,16 @@ where
     T: for<'a> BinWrite<Args<'a> = ()>,
 {
     /// The data alignment factor for little-endian architectures.
-    const ALIGNMENT_LE: u64 = 4;
+    pub const ALIGNMENT_LE: u64 = 4;
+
+    /// The size of the magic, hash digest and data size preceding the data, in bytes.
+    pub const HEADER_SIZE: usize = size_of::<u32>() + size_of::<HashCode>() + size_of::<u32>();
+
+    /// Returns the size of a data block holding `data_size` bytes of data, in
+    /// little-endian byte order.
+    pub const fn size_le(data_size: usize) -> usize {
+        (Self::HEADER_SIZE + data_size).next_multiple_of(Self::ALIGNMENT_LE as usize)
+    }
 }
 
 impl<T> BinRead for BinaryDataChunk<T>
@@ -55,7 +64,10 @@ where
     ) -> BinResult<Self> {
         let hash_pos = reader.stream_position()?;
         let hash = HashCode::read_options(reader, endian, ())?;
-        let expected_hash = T::hash_code();
+
+        let expected_data_size = u32::read_options(reader, endian, ())? as usize;
+        let inner = T::read_options(reader, endian, (expected_data_size - Self::HEADER_SIZE,))?;
+        let expected_hash = inner.dyn_hash_code(endian);
 
         if hash != expected_hash {
             return Err(binrw::Error::AssertFail {
@@ -68,9 +80,6 @@ where
             });
         }
 
-        let header_size = size_of::<u32>() + size_of::<HashCode>() + size_of::<u32>();
-        let expected_data_size = u32::read_options(reader, endian, ())? as usize;
-        let inner = T::read_options(reader, endian, (expected_data_size - header_size,))?;
         let end_pos = match endian {
             Endian::Big => reader.stream_position()?,
             Endian::Little => reader
@@ -110,7 +119,7 @@ where
         _args: Self::Args<'_>,
     ) -> BinResult<()> {
         let start_pos = writer.stream_position()? - size_of::<u32>() as u64;
-        let hash_code = T::hash_code();
+        let hash_code = self.inner.dyn_hash_code(endian);
 
         hash_code.write_options(writer, endian, ())?;
 
@@ -140,4 +149,12 @@ where
 pub trait Chunk {
     /// Returns the hash digest identifying the data block.
     fn hash_code() -> HashCode;
+
+    /// Returns the hash digest identifying the data block storing `self` in the given byte order.
+    ///
+    /// This differs from [`hash_code`](Chunk::hash_code) for data blocks whose
+    /// hash digest depends on the fields present or the platform.
+    fn dyn_hash_code(&self, _endian: Endian) -> HashCode {
+        Self::hash_code()
+    }
 }
@@ -1,4 +1,7 @@
-use binrw::{BinRead, BinWrite, binrw};
+use std::io::{Seek, Write};
+
+use binrw::{BinRead, BinResult, BinWrite, Endian, binrw, io::Cursor};
+use thiserror::Error;
 
 #[cfg(feature = "serde")]
 use serde::{Deserialize, Serialize};
@@ -30,7 +33,8 @@ where
 
     /// The collection of data blocks.
     #[br(count = chunk_num as usize)]
-    #[brw(pad_size_to = T::BUFFER_SIZE - size_of::<u32>())]
+    #[br(pad_size_to = T::BUFFER_SIZE - size_of::<u32>())]
+    #[bw(write_with = write_chunks)]
     pub chunks: Vec<T>,
 }
 
@@ -41,4 +45,94 @@ pub trait ChunkHolder {
 
     /// The version number of the container.
     const VERSION: u8;
+
+    /// The size of the container's data buffer available to data blocks, in bytes.
+    const CAPACITY: usize = Self::BUFFER_SIZE - size_of::<u32>();
+
+    /// Returns the number of bytes of the container's data buffer left unused
+    /// by the data blocks.
+    ///
+    /// The data blocks are measured in little-endian byte order, whose
+    /// alignment never makes them smaller than in big-endian byte order.
+    fn remaining_capacity(chunks: &[Self]) -> BinResult<usize>
+    where
+        Self: Sized + BinWrite + 'static,
+        Self: for<'a> BinWrite<Args<'a> = ()>,
+    {
+        let mut writer = Cursor::new(Vec::new());
+
+        for chunk in chunks {
+            chunk.write_le(&mut writer)?;
+        }
+
+        Ok(Self::CAPACITY.saturating_sub(writer.position() as usize))
+    }
+
+    /// Checks that the data blocks still fit within the container's data
+    /// buffer after growing by `additional` bytes, as measured by
+    /// [`remaining_capacity`](Self::remaining_capacity).
+    fn check_capacity(chunks: &[Self], additional: usize) -> Result<(), CapacityError>
+    where
+        Self: Sized + BinWrite + 'static,
+        Self: for<'a> BinWrite<Args<'a> = ()>,
+    {
+        let remaining_capacity = Self::remaining_capacity(chunks)?;
+
+        if additional > remaining_capacity {
+            return Err(BufferOverflowError {
+                size: (Self::CAPACITY - remaining_capacity + additional) as u64,
+                capacity: Self::CAPACITY as u64,
+            }
+            .into());
+        }
+
+        Ok(())
+    }
+}
+
+/// An error returned from writing data blocks which exceed the container's data buffer.
+#[derive(Debug, Error)]
+#[error("the data blocks require {size} bytes, exceeding the buffer capacity of {capacity} bytes")]
+pub struct BufferOverflowError {
+    /// The serialized size of the data blocks, in bytes.
+    pub size: u64,
+
+    /// The size of the container's data buffer available to data blocks, in bytes.
+    pub capacity: u64,
+}
+
+/// An error returned from checking that data blocks fit within the container's data buffer.
+#[derive(Debug, Error)]
+pub enum CapacityError {
+    /// The data blocks would exceed the container's data buffer.
+    #[error(transparent)]
+    BufferOverflow(#[from] BufferOverflowError),
+
+    /// An error occurred while measuring the data blocks.
+    #[error(transparent)]
+    Binrw(#[from] binrw::Error),
+}
+
+/// Writes the data blocks, padding to the size of the container's data buffer.
+fn write_chunks<T, W>(chunks: &Vec<T>, writer: &mut W, endian: Endian, _args: ()) -> BinResult<()>
+where
+    T: BinWrite + ChunkHolder + 'static,
+    T: for<'a> BinWrite<Args<'a> = ()>,
+    W: Write + Seek,
+{
+    let start_pos = writer.stream_position()?;
+    let capacity = T::CAPACITY as u64;
+
+    chunks.write_options(writer, endian, ())?;
+
+    let size = writer.stream_position()? - start_pos;
+
+    if size > capacity {
+        return Err(binrw::Error::Custom {
+            pos: start_pos,
+            err: Box::new(BufferOverflowError { size, capacity }),
+        });
+    }
+
+    vec![0u8; (capacity - size) as usize].write_options(writer, endian, ())
 }
@@ -1,6 +1,9 @@
-use std::marker::PhantomData;
+use std::{
+    io::{Read, Seek, SeekFrom, Write},
+    marker::PhantomData,
+};
 
-use binrw::binrw;
+use binrw::{BinRead, BinResult, BinWrite, Endian, binrw};
 
 #[cfg(feature = "serde")]
 use serde::{Deserialize, Serialize};
@@ -10,6 +13,7 @@ use crate::hash::HashCode16;
 /// The dynamic reader/writer for the content of a data block.
 #[binrw]
 #[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+#[derive(Debug)]
 pub struct BinaryDataContentHeaderSerializer<T>
 where
     T: HeaderSerializer,
@@ -20,9 +24,7 @@ where
     attribute_num: u16,
 
     /// The size of the serialized struct `T`, in bytes.
-    #[br(temp)]
-    #[bw(calc = T::data_size() as u16)]
-    _data_size: u16,
+    data_size: u16,
 
     /// The collection of field descriptors.
     #[br(count = attribute_num as usize)]
@@ -32,21 +34,149 @@ where
     phantom: PhantomData<T>,
 }
 
-impl<T> From<Vec<BinaryDataContentAttribute>> for BinaryDataContentHeaderSerializer<T>
+impl<T> BinaryDataContentHeaderSerializer<T>
 where
     T: HeaderSerializer,
 {
-    fn from(attributes: Vec<BinaryDataContentAttribute>) -> Self {
+    /// Creates a new `BinaryDataContentHeaderSerializer` for serialized field data of `data_size` bytes.
+    pub fn new(attributes: Vec<BinaryDataContentAttribute>, data_size: usize) -> Self {
         Self {
+            data_size: data_size as u16,
             attributes,
             phantom: PhantomData::<T>,
         }
     }
+
+    /// Returns `true` if a field descriptor exists for the key.
+    pub fn contains(&self, key: impl Into<HashCode16>) -> bool {
+        let key = key.into();
+
+        self.attributes.iter().any(|a| a.key == key)
+    }
+
+    /// Returns the size of the serialized struct `T`, in bytes.
+    pub fn data_size(&self) -> usize {
+        self.data_size as usize
+    }
+
+    /// Returns the serialized size of `self`, in bytes.
+    pub fn header_size(&self) -> usize {
+        size_of::<u16>()
+            + size_of::<u16>()
+            + self.attributes.len() * size_of::<BinaryDataContentAttribute>()
+    }
+
+    /// Returns the offset to the field corresponding to the key, relative to
+    /// the start of the serialized field data.
+    pub fn offset_of(&self, key: impl Into<HashCode16>) -> Option<u64> {
+        let key = key.into();
+
+        self.attributes
+            .iter()
+            .find(|a| a.key == key)
+            .map(|a| a.offset as u64)
+    }
+
+    /// Returns a copy of `self` extended with the field descriptors of `other`
+    /// which `self` lacks.
+    ///
+    /// The fields of `self` keep their offsets, and each added field is
+    /// appended to the serialized field data with its size in `other`.
+    pub fn widen(&self, other: &Self) -> Self {
+        let mut attributes = self.attributes.clone();
+        let mut data_size = self.data_size;
+
+        for attribute in &other.attributes {
+            if self.contains(attribute.key) {
+                continue;
+            }
+
+            let end = other
+                .attributes
+                .iter()
+                .map(|a| a.offset)
+                .filter(|offset| *offset > attribute.offset)
+                .min()
+                .unwrap_or(other.data_size);
+
+            attributes.push(BinaryDataContentAttribute {
+                key: attribute.key,
+                offset: data_size,
+            });
+            data_size += end - attribute.offset;
+        }
+
+        Self {
+            data_size,
+            attributes,
+            phantom: PhantomData::<T>,
+        }
+    }
+
+    /// Reads the field corresponding to the key at its offset from `start`,
+    /// the position of the serialized field data.
+    ///
+    /// Returns `None` if no field descriptor exists for the key.
+    pub fn read_field<F, R>(
+        &self,
+        key: impl Into<HashCode16>,
+        reader: &mut R,
+        endian: Endian,
+        start: u64,
+    ) -> BinResult<Option<F>>
+    where
+        F: for<'a> BinRead<Args<'a> = ()>,
+        R: Read + Seek,
+    {
+        let Some(offset) = self.offset_of(key) else {
+            return Ok(None);
+        };
+
+        reader.seek(SeekFrom::Start(start + offset))?;
+
+        F::read_options(reader, endian, ()).map(Some)
+    }
+
+    /// Writes the field corresponding to the key at its offset from `start`,
+    /// the position of the serialized field data.
+    ///
+    /// Nothing is written if the value is absent or no field descriptor exists
+    /// for the key.
+    pub fn write_field<F, W>(
+        &self,
+        key: impl Into<HashCode16>,
+        value: Option<&F>,
+        writer: &mut W,
+        endian: Endian,
+        start: u64,
+    ) -> BinResult<()>
+    where
+        F: for<'a> BinWrite<Args<'a> = ()>,
+        W: Write + Seek,
+    {
+        let (Some(offset), Some(value)) = (self.offset_of(key), value) else {
+            return Ok(());
+        };
+
+        writer.seek(SeekFrom::Start(start + offset))?;
+
+        value.write_options(writer, endian, ())
+    }
+}
+
+impl<T> From<Vec<BinaryDataContentAttribute>> for BinaryDataContentHeaderSerializer<T>
+where
+    T: HeaderSerializer,
+{
+    fn from(attributes: Vec<BinaryDataContentAttribute>) -> Self {
+        Self::new(attributes, T::data_size())
+    }
 }
 
 /// The descriptor for a field stored in a data block.
 #[binrw]
 #[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
 pub struct BinaryDataContentAttribute {
     /// The hashed name of the serialized field, truncated to the least significant 16 bits.
     pub key: HashCode16,
@@ -56,6 +186,9 @@ pub struct BinaryDataContentAttribute {
 }
 
 /// A trait for types which must support storing a `BinaryDataContentHeaderSerializer`.
+///
+/// The associated functions describe the fields which are always present,
+/// while the methods also describe the optional fields present in `self`.
 pub trait HeaderSerializer: Sized {
     /// Creates a new `BinaryDataContentHeaderSerializer`.
     fn header_serializer() -> BinaryDataContentHeaderSerializer<Self>;
@@ -65,4 +198,13 @@ pub trait HeaderSerializer: Sized {
 
     /// Returns the serialized size of `Self`, in bytes.
     fn data_size() -> usize;
+
+    /// Creates a new `BinaryDataContentHeaderSerializer` describing the fields present in `self`.
+    fn dyn_header_serializer(&self) -> BinaryDataContentHeaderSerializer<Self>;
+
+    /// Returns the serialized size of the `BinaryDataContentHeaderSerializer` describing `self`, in bytes.
+    fn dyn_header_size(&self) -> usize;
+
+    /// Returns the serialized size of `self`, in bytes.
+    fn dyn_data_size(&self) -> usize;
 }
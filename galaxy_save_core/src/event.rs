@@ -1,7 +1,34 @@
 //! Basic key-value pair utilities.
 
+use thiserror::Error;
+
+use crate::{
+    bin::CapacityError,
+    hash::{ParseHexError, ParseLabelError},
+};
+
 mod flag;
 mod value;
 
 pub use flag::GameEventFlag;
 pub use value::GameEventValue;
+
+/// An error returned from inserting key-value pairs into a container of data blocks.
+#[derive(Debug, Error)]
+pub enum InsertEventError {
+    /// The data block storing the key-value pairs is missing.
+    #[error("the user file has no storage for the key-value pairs")]
+    MissingChunk,
+
+    /// An error occurred while converting a hexadecimal string into a hash.
+    #[error(transparent)]
+    ParseHex(#[from] ParseHexError),
+
+    /// An error occurred while hashing a label.
+    #[error(transparent)]
+    ParseLabel(#[from] ParseLabelError),
+
+    /// An error occurred while checking that the data blocks fit within the data buffer.
+    #[error(transparent)]
+    Capacity(#[from] CapacityError),
+}
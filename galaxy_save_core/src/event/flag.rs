@@ -23,7 +23,7 @@ impl GameEventFlag {
     const KEY_MASK: u16 = !Self::VALUE_MASK;
 
     /// The bit width of the hashed key.
-    const KEY_WIDTH: u32 = u16::BITS - 1;
+    pub const KEY_WIDTH: u32 = u16::BITS - 1;
 
     /// The bitmask for the associated value.
     const VALUE_MASK: u16 = 1 << Self::KEY_WIDTH;
@@ -40,7 +40,7 @@ impl GameEventFlag {
     }
 
     /// Extracts the hashed key.
-    const fn key(&self) -> u16 {
+    pub const fn key(&self) -> u16 {
         self.inner & Self::KEY_MASK
     }
 
@@ -65,6 +65,8 @@ impl PartialEq<HashCode> for GameEventFlag {
 #[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
 struct ExpandedGameEventFlag {
     key: String,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    translation: Option<String>,
     value: bool,
 }
 
@@ -73,9 +75,11 @@ impl From<GameEventFlag> for ExpandedGameEventFlag {
     fn from(flag: GameEventFlag) -> Self {
         let hash = HashCode::from_raw(flag.key().into());
         let label = hash.to_label(Some(GameEventFlag::KEY_WIDTH));
+        let translation = hash.to_translation(Some(GameEventFlag::KEY_WIDTH));
 
         Self {
             key: label,
+            translation,
             value: flag.value(),
         }
     }
@@ -8,7 +8,11 @@ use crate::hash::{HashCode, HashCode16};
 /// A key-value pair for a 16-bit unsigned integer.
 #[binrw]
 #[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
-#[derive(Debug)]
+#[cfg_attr(
+    feature = "serde",
+    serde(from = "ExpandedGameEventValue", into = "ExpandedGameEventValue")
+)]
+#[derive(Debug, Clone, Copy)]
 pub struct GameEventValue {
     /// The hashed key, truncated to the least significant 16 bits.
     key: HashCode16,
@@ -26,7 +30,7 @@ impl GameEventValue {
     }
 
     /// Returns the hashed key.
-    const fn key(&self) -> HashCode16 {
+    pub const fn key(&self) -> HashCode16 {
         self.key
     }
 
@@ -46,3 +50,35 @@ impl PartialEq<HashCode> for GameEventValue {
         self.key() == *other
     }
 }
+
+#[cfg(feature = "serde")]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+struct ExpandedGameEventValue {
+    key: HashCode16,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    translation: Option<String>,
+    value: u16,
+}
+
+#[cfg(feature = "serde")]
+impl From<GameEventValue> for ExpandedGameEventValue {
+    fn from(value: GameEventValue) -> Self {
+        let translation = HashCode::from(value.key).to_translation(Some(u16::BITS));
+
+        Self {
+            key: value.key,
+            translation,
+            value: value.value,
+        }
+    }
+}
+
+#[cfg(feature = "serde")]
+impl From<ExpandedGameEventValue> for GameEventValue {
+    fn from(value: ExpandedGameEventValue) -> Self {
+        Self {
+            key: value.key,
+            value: value.value,
+        }
+    }
+}
@@ -1,19 +1,26 @@
 //! Basic hash utilities.
 
-use std::num::ParseIntError;
+use std::{borrow::Cow, num::ParseIntError};
 
 use binrw::binrw;
 use thiserror::Error;
 
+use crate::save::Platform;
+
 #[cfg(feature = "serde")]
 use serde::{Deserialize, Deserializer, Serialize, Serializer};
 
 mod map;
+mod recover;
 
 #[cfg(test)]
 mod tests;
 
-pub use map::{HashCodeMap, ParseLabelError};
+pub use map::{
+    Encoding, HashCodeMap, HashCodeMapGuard, LabelCategory, LabelCollision, LabelInfo,
+    ParseLabelError,
+};
+pub use recover::{LabelRecovery, RecoverLabelError, RecoveredLabel};
 
 /// The wrapper type for the result of the hash function.
 ///
@@ -69,6 +76,16 @@ impl HashCode {
         }
     }
 
+    /// Creates a new `HashCode` from a string converted to Shift JIS.
+    pub fn from_shift_jis(s: &str) -> Result<Self, ParseLabelError> {
+        Encoding::ShiftJis.hash(s)
+    }
+
+    /// Creates a new `HashCode` from a label converted to the character encoding of the platform.
+    pub fn for_label(label: &str, platform: Platform) -> Result<Self, ParseLabelError> {
+        platform.encoding().hash(label)
+    }
+
     /// Converts a label or hexadecimal string into a `HashCode`.
     ///
     /// To convert a string into a `HashCode` with only necessary operations,
@@ -77,7 +94,7 @@ impl HashCode {
         match Self::from_hex_str(label) {
             Ok(hash) => Ok(hash),
             Err(error) => match error {
-                ParseHexError::MissingPrefix => HashCodeMap::get()
+                ParseHexError::MissingPrefix => HashCodeMap::current()
                     .lock()
                     .hash_of(label)
                     .ok_or_else(|| FromLabelError::NotFound(label.into())),
@@ -86,19 +103,18 @@ impl HashCode {
         }
     }
 
-    /// Converts a `HashCode` back to its original label, or hexadecimal if not found.
-    pub fn to_label(self, width: Option<u32>) -> String {
-        HashCodeMap::get()
+    /// Returns the translation of the original label, if translations are enabled and one is known.
+    #[cfg(feature = "serde")]
+    pub(crate) fn to_translation(self, width: Option<u32>) -> Option<String> {
+        HashCodeMap::current()
             .lock()
-            .label_of(self, width)
+            .translation_of(self, width)
             .cloned()
-            .unwrap_or_else(|| format!("{:#X}", self.inner))
     }
-}
 
-impl From<&[u8]> for HashCode {
-    fn from(buf: &[u8]) -> Self {
-        let mut hash = 0u32;
+    /// Continues the hash function with the bytes of another buffer.
+    pub(crate) fn update(self, buf: &[u8]) -> Self {
+        let mut hash = self.inner;
 
         // Each byte is first casted to a signed byte because a value like
         // 0x80u8 should convert to 0xFFFFFF80u32 instead of 0x80u32.
@@ -108,6 +124,21 @@ impl From<&[u8]> for HashCode {
 
         Self { inner: hash }
     }
+
+    /// Converts a `HashCode` back to its original label, or hexadecimal if not found.
+    pub fn to_label(self, width: Option<u32>) -> String {
+        HashCodeMap::current()
+            .lock()
+            .label_of(self, width)
+            .cloned()
+            .unwrap_or_else(|| format!("{:#X}", self.inner))
+    }
+}
+
+impl From<&[u8]> for HashCode {
+    fn from(buf: &[u8]) -> Self {
+        Self::default().update(buf)
+    }
 }
 
 impl From<&String> for HashCode {
@@ -185,6 +216,11 @@ impl HashCode16 {
         HashCode::from_hex_str(s).map(|h| h.into())
     }
 
+    /// Creates a new `HashCode16` from a string converted to Shift JIS.
+    pub fn from_shift_jis(s: &str) -> Result<Self, ParseLabelError> {
+        HashCode::from_shift_jis(s).map(|h| h.into())
+    }
+
     /// Converts a label or hexadecimal string into a `HashCode16`.
     pub fn from_label(label: &str) -> Result<Self, FromLabelError> {
         HashCode::from_label(label).map(|h| h.into())
@@ -252,6 +288,77 @@ impl<'de> Deserialize<'de> for HashCode16 {
     }
 }
 
+/// A key identifying a key-value pair, either by hash or by label.
+///
+/// A label matches its hash under any character encoding, as the encoding
+/// keys were hashed under depends on the platform of the save file.
+#[derive(Debug, Clone, PartialEq)]
+pub enum HashKey<'a> {
+    /// A precomputed hash.
+    Hash(HashCode),
+
+    /// An unhashed label.
+    Label(Cow<'a, str>),
+}
+
+impl HashKey<'_> {
+    /// Returns the hash of the key, hashing a label under the character encoding.
+    pub fn hash_code(&self, encoding: Encoding) -> Result<HashCode, ParseLabelError> {
+        match self {
+            Self::Hash(hash) => Ok(*hash),
+            Self::Label(label) => encoding.hash(label),
+        }
+    }
+
+    /// Returns every hash the key may be stored under.
+    ///
+    /// A label is hashed under each character encoding able to represent it,
+    /// in the order of [`Encoding::ALL`], omitting duplicate hashes.
+    pub fn hash_codes(&self) -> Vec<HashCode> {
+        let mut hashes = Vec::new();
+
+        for encoding in Encoding::ALL {
+            if let Ok(hash) = self.hash_code(encoding)
+                && !hashes.contains(&hash)
+            {
+                hashes.push(hash);
+            }
+        }
+
+        hashes
+    }
+}
+
+impl From<HashCode> for HashKey<'_> {
+    fn from(hash: HashCode) -> Self {
+        Self::Hash(hash)
+    }
+}
+
+impl From<HashCode16> for HashKey<'_> {
+    fn from(hash: HashCode16) -> Self {
+        Self::Hash(hash.into())
+    }
+}
+
+impl<'a> From<&'a str> for HashKey<'a> {
+    fn from(label: &'a str) -> Self {
+        Self::Label(Cow::Borrowed(label))
+    }
+}
+
+impl<'a> From<&'a String> for HashKey<'a> {
+    fn from(label: &'a String) -> Self {
+        Self::Label(Cow::Borrowed(label))
+    }
+}
+
+impl From<String> for HashKey<'_> {
+    fn from(label: String) -> Self {
+        Self::Label(Cow::Owned(label))
+    }
+}
+
 /// An error returned from converting a hexadecimal string into a [`HashCode`].
 #[derive(Debug, Error)]
 pub enum ParseHexError {
@@ -1,31 +1,58 @@
 use std::{
+    cell::RefCell,
+    collections::HashMap,
     fs::File,
     io::{self, BufRead, BufReader},
+    marker::PhantomData,
     path::Path,
+    str::FromStr,
     sync::{Arc, LazyLock},
 };
 
-use bimap::BiHashMap;
 use encoding_rs::SHIFT_JIS;
 use parking_lot::Mutex;
 use thiserror::Error;
 
 use super::HashCode;
+use crate::event::GameEventFlag;
+
+thread_local! {
+    /// The `HashCodeMap` in use by the current thread, overriding the global instance.
+    static SCOPED: RefCell<Option<Arc<Mutex<HashCodeMap>>>> = const { RefCell::new(None) };
+}
 
 /// A container for associating hashes with their original label and vice versa.
+///
+/// Each label may be hashed under more than one character encoding, so a label
+/// can correspond to several hashes.
 #[derive(Debug, Default)]
 pub struct HashCodeMap {
-    /// The collection of bidirectional relations between hashes and labels.
-    map: BiHashMap<HashCode, String>,
+    /// The collection of labels, keyed by hash.
+    labels: HashMap<HashCode, String>,
+
+    /// The collection of hashes for each character encoding, keyed by label.
+    hashes: HashMap<String, Vec<(Encoding, HashCode)>>,
+
+    /// The collection of full hashes grouped by truncated hash, for each of the truncated widths.
+    truncated: [HashMap<u32, Vec<HashCode>>; Self::TRUNCATED_WIDTHS.len()],
 
-    /// The configured character encoding for labels.
+    /// The collection of annotations, keyed by label.
+    info: HashMap<String, LabelInfo>,
+
+    /// The preferred character encoding when looking up the hash of a label.
     encoding: Option<Encoding>,
 
     /// Determines if foreign labels should be rejected when looking up the hash of a label.
     strict: bool,
+
+    /// Determines if translations should accompany labels when serializing.
+    translated: bool,
 }
 
 impl HashCodeMap {
+    /// The bit widths hashes are truncated to when stored in save data.
+    pub const TRUNCATED_WIDTHS: [u32; 2] = [GameEventFlag::KEY_WIDTH, u16::BITS];
+
     /// Returns a thread-safe reference-counting pointer to the mutually exclusive `HashCodeMap`.
     pub fn get() -> Arc<Mutex<Self>> {
         static INSTANCE: LazyLock<Arc<Mutex<HashCodeMap>>> =
@@ -34,28 +61,153 @@ impl HashCodeMap {
         INSTANCE.clone()
     }
 
+    /// Returns the `HashCodeMap` in use by the current thread.
+    ///
+    /// This is the map most recently entered with [`enter`](Self::enter), or
+    /// the global instance from [`get`](Self::get) otherwise.
+    pub fn current() -> Arc<Mutex<Self>> {
+        SCOPED
+            .with_borrow(|scoped| scoped.clone())
+            .unwrap_or_else(Self::get)
+    }
+
+    /// Uses a `HashCodeMap` on the current thread until the returned guard is dropped.
+    ///
+    /// Hash conversions, including serialization and deserialization, will
+    /// look up labels in this map instead of the global instance.
+    pub fn enter(map: Arc<Mutex<Self>>) -> HashCodeMapGuard {
+        HashCodeMapGuard {
+            previous: SCOPED.replace(Some(map)),
+            _marker: PhantomData,
+        }
+    }
+
     /// Returns a reference to the label corresponding to the hash.
+    ///
+    /// If a width is given, only the least significant bits of the hash are
+    /// compared. Truncated hashes shared by more than one label are ambiguous
+    /// and will return `None`; use [`labels_of`](Self::labels_of) to retrieve
+    /// every candidate.
     pub fn label_of(&self, hash: HashCode, width: Option<u32>) -> Option<&String> {
         match width {
-            None => self.map.get_by_left(&hash),
-            Some(width) => {
-                let mask = u32::MAX >> (u32::BITS - width);
-                let hash = hash.into_raw() & mask;
+            None => self.labels.get(&hash),
+            Some(_) => match self.labels_of(hash, width).as_slice() {
+                [label] => Some(*label),
+                _ => None,
+            },
+        }
+    }
+
+    /// Returns a sorted collection of references to every label corresponding to the hash.
+    ///
+    /// If a width is given, only the least significant bits of the hash are
+    /// compared.
+    pub fn labels_of(&self, hash: HashCode, width: Option<u32>) -> Vec<&String> {
+        let Some(width) = width else {
+            return self.labels.get(&hash).into_iter().collect();
+        };
+
+        let mask = truncation_mask(width);
+        let hash = hash.into_raw() & mask;
+        let mut labels = match Self::TRUNCATED_WIDTHS.iter().position(|w| *w == width) {
+            Some(i) => self.truncated[i]
+                .get(&hash)
+                .into_iter()
+                .flatten()
+                .filter_map(|k| self.labels.get(k))
+                .collect::<Vec<_>>(),
+            None => self
+                .labels
+                .iter()
+                .filter(|(k, _)| k.into_raw() & mask == hash)
+                .map(|(_, v)| v)
+                .collect::<Vec<_>>(),
+        };
+
+        labels.sort_unstable();
+        labels.dedup();
+        labels
+    }
 
-                self.map
+    /// Returns every group of labels sharing a truncated hash, sorted by width and hash.
+    pub fn collisions(&self) -> Vec<LabelCollision> {
+        let mut collisions = Self::TRUNCATED_WIDTHS
+            .iter()
+            .zip(&self.truncated)
+            .flat_map(|(&width, index)| {
+                index
                     .iter()
-                    .find(|(k, _)| k.into_raw() & mask == hash)
-                    .map(|(_, v)| v)
-            }
+                    .filter(|(_, hashes)| hashes.len() > 1)
+                    .map(move |(&hash, _)| {
+                        let hash = HashCode::from_raw(hash);
+                        let labels = self
+                            .labels_of(hash, Some(width))
+                            .into_iter()
+                            .cloned()
+                            .collect();
+
+                        LabelCollision {
+                            width,
+                            hash,
+                            labels,
+                        }
+                    })
+            })
+            .filter(|collision| collision.labels.len() > 1)
+            .collect::<Vec<_>>();
+
+        collisions.sort_unstable_by_key(|c| (c.width, c.hash.into_raw()));
+        collisions
+    }
+
+    /// Returns a reference to the annotations of the label corresponding to the hash.
+    ///
+    /// If a width is given, only the least significant bits of the hash are
+    /// compared.
+    pub fn info_of(&self, hash: HashCode, width: Option<u32>) -> Option<&LabelInfo> {
+        let label = self.label_of(hash, width)?;
+
+        self.info.get(label)
+    }
+
+    /// Returns the translation of the label corresponding to the hash, if translations are enabled.
+    pub fn translation_of(&self, hash: HashCode, width: Option<u32>) -> Option<&String> {
+        if !self.translated {
+            return None;
         }
+
+        self.info_of(hash, width)?.translation.as_ref()
     }
 
     /// Returns the hash corresponding to the label.
+    ///
+    /// The hash under the preferred character encoding is returned if
+    /// possible, otherwise the hash under any known encoding.
     pub fn hash_of(&self, label: &str) -> Option<HashCode> {
-        self.map
-            .get_by_right(label)
-            .copied()
-            .or_else(|| (!self.strict).then(|| HashCode::from(label)))
+        let preferred = self.encoding.and_then(|e| e.hash(label).ok());
+
+        let Some(hashes) = self.hashes.get(label) else {
+            return (!self.strict).then(|| preferred.unwrap_or_else(|| HashCode::from(label)));
+        };
+
+        hashes
+            .iter()
+            .find(|(e, _)| Some(*e) == self.encoding)
+            .map(|(_, h)| *h)
+            .or(preferred)
+            .or_else(|| hashes.first().map(|(_, h)| *h))
+    }
+
+    /// Hashes and inserts a collection of labels from an iterator.
+    ///
+    /// Labels are hashed under the preferred character encoding if configured,
+    /// otherwise under every character encoding, skipping encodings unable to
+    /// represent a label.
+    pub fn extend<I: IntoIterator<Item = String>>(
+        &mut self,
+        iter: I,
+    ) -> Result<(), ParseLabelError> {
+        self.extend_with(iter, &self.encodings())
     }
 
     /// Hashes and inserts a collection of labels from an iterator, converting to Shift JIS.
@@ -63,7 +215,7 @@ impl HashCodeMap {
         &mut self,
         iter: I,
     ) -> Result<(), ParseLabelError> {
-        self.extend(iter, Encoding::ShiftJis, encode_shift_jis)
+        self.extend_with(iter, &[Encoding::ShiftJis])
     }
 
     /// Hashes and inserts a collection of labels from an iterator, interpreting as UTF-8.
@@ -71,72 +223,143 @@ impl HashCodeMap {
         &mut self,
         iter: I,
     ) -> Result<(), ParseLabelError> {
-        self.extend(iter, Encoding::Utf8, encode_utf8)
+        self.extend_with(iter, &[Encoding::Utf8])
     }
 
-    /// Hashes and inserts a collection of labels from an iterator.
-    fn extend<I, F>(
-        &mut self,
-        iter: I,
-        encoding: Encoding,
-        encode: F,
-    ) -> Result<(), ParseLabelError>
+    /// Hashes and inserts a collection of labels from an iterator under the given encodings.
+    fn extend_with<I>(&mut self, iter: I, encodings: &[Encoding]) -> Result<(), ParseLabelError>
     where
         I: IntoIterator<Item = String>,
-        F: Fn(String) -> Result<(HashCode, String), ParseLabelError>,
     {
-        if *self.encoding.get_or_insert(encoding) != encoding {
-            return Err(ParseLabelError::InconsistentEncoding);
-        }
-
         for label in iter {
-            let (hash, label) = encode(label)?;
+            let hashes = hash_label(&label, encodings)?;
 
-            self.map.insert(hash, label);
+            self.insert(label, hashes);
         }
 
         Ok(())
     }
 
+    /// Reads and hashes a newline-separated list of labels from a file.
+    ///
+    /// Labels are hashed under the preferred character encoding if configured,
+    /// otherwise under every character encoding, skipping encodings unable to
+    /// represent a label. Each line may be followed by tab-separated
+    /// annotations in the order of category, translation and notes.
+    pub fn read<P: AsRef<Path>>(&mut self, path: P) -> Result<(), ParseLabelError> {
+        self.read_with(path, &self.encodings())
+    }
+
     /// Reads and hashes a newline-separated list of labels from a file, converting to Shift JIS.
+    ///
+    /// Each line may be followed by tab-separated annotations in the order of
+    /// category, translation and notes.
     pub fn read_shift_jis<P: AsRef<Path>>(&mut self, path: P) -> Result<(), ParseLabelError> {
-        self.read(path, Encoding::ShiftJis, encode_shift_jis)
+        self.read_with(path, &[Encoding::ShiftJis])
     }
 
     /// Reads and hashes a newline-separated list of labels from a file, interpreting as UTF-8.
+    ///
+    /// Each line may be followed by tab-separated annotations in the order of
+    /// category, translation and notes.
     pub fn read_utf8<P: AsRef<Path>>(&mut self, path: P) -> Result<(), ParseLabelError> {
-        self.read(path, Encoding::Utf8, encode_utf8)
+        self.read_with(path, &[Encoding::Utf8])
     }
 
-    /// Reads and hashes a newline-separated list of labels from a file.
-    fn read<P, F>(&mut self, path: P, encoding: Encoding, encode: F) -> Result<(), ParseLabelError>
+    /// Reads and hashes a newline-separated list of labels from a file under the given encodings.
+    fn read_with<P>(&mut self, path: P, encodings: &[Encoding]) -> Result<(), ParseLabelError>
     where
         P: AsRef<Path>,
-        F: Fn(String) -> Result<(HashCode, String), ParseLabelError>,
     {
-        if *self.encoding.get_or_insert(encoding) != encoding {
-            return Err(ParseLabelError::InconsistentEncoding);
-        }
-
         let reader = BufReader::new(File::open(path)?);
-        let map = reader
+        let entries = reader
             .lines()
-            .map(|l| encode(l?))
-            .collect::<Result<BiHashMap<_, _>, _>>()?;
+            .map(|l| {
+                let (label, info) = parse_line(l?)?;
+                let hashes = hash_label(&label, encodings)?;
+
+                Ok((label, hashes, info))
+            })
+            .collect::<Result<Vec<_>, ParseLabelError>>()?;
 
-        self.map.extend(map);
+        for (label, hashes, info) in entries {
+            if let Some(info) = info {
+                self.info.insert(label.clone(), info);
+            }
+
+            self.insert(label, hashes);
+        }
 
         Ok(())
     }
 
+    /// Returns the encodings to hash labels under when none are specified.
+    fn encodings(&self) -> Vec<Encoding> {
+        match self.encoding {
+            Some(encoding) => vec![encoding],
+            None => Encoding::ALL.to_vec(),
+        }
+    }
+
+    /// Inserts a label with its hash under each encoding, keeping the truncated hash indexes up to date.
+    fn insert(&mut self, label: String, hashes: Vec<(Encoding, HashCode)>) {
+        for (encoding, hash) in hashes {
+            match self.labels.insert(hash, label.clone()) {
+                Some(previous) if previous != label => self.forget(&previous, hash),
+                Some(_) => {}
+                None => {
+                    for (&width, index) in Self::TRUNCATED_WIDTHS.iter().zip(&mut self.truncated) {
+                        index
+                            .entry(hash.into_raw() & truncation_mask(width))
+                            .or_default()
+                            .push(hash);
+                    }
+                }
+            }
+
+            let variants = self.hashes.entry(label.clone()).or_default();
+
+            if !variants.contains(&(encoding, hash)) {
+                variants.push((encoding, hash));
+            }
+        }
+    }
+
+    /// Removes a hash from the variants of a label replaced by another label with the same hash.
+    fn forget(&mut self, label: &str, hash: HashCode) {
+        let Some(variants) = self.hashes.get_mut(label) else {
+            return;
+        };
+
+        variants.retain(|(_, h)| *h != hash);
+
+        if variants.is_empty() {
+            self.hashes.remove(label);
+            self.info.remove(label);
+        }
+    }
+
+    /// Updates the preferred character encoding when looking up the hash of a label.
+    pub fn set_encoding(&mut self, encoding: Option<Encoding>) {
+        self.encoding = encoding;
+    }
+
     /// Updates whether foreign labels should be rejected when looking up the hash of a label.
     pub fn set_strict(&mut self, strict: bool) {
         self.strict = strict;
     }
 
+    /// Updates whether translations should accompany labels when serializing.
+    pub fn set_translated(&mut self, translated: bool) {
+        self.translated = translated;
+    }
+
     /// Clears the map, removing all key-value pairs. Keeps the allocated memory for reuse.
     pub fn clear(&mut self) {
-        self.map.clear();
+        self.labels.clear();
+        self.hashes.clear();
+        self.info.clear();
+        self.truncated.iter_mut().for_each(HashMap::clear);
     }
 
     /// Clears the map, removing all key-value pairs and resets additional configuration. Keeps the allocated memory for reuse.
@@ -144,16 +367,82 @@ impl HashCodeMap {
         self.clear();
         self.encoding = None;
         self.strict = false;
+        self.translated = false;
+    }
+}
+
+/// A guard restoring the previously used `HashCodeMap` of the current thread when dropped.
+#[must_use = "the map is only used until the guard is dropped"]
+#[derive(Debug)]
+pub struct HashCodeMapGuard {
+    /// The map in use before entering.
+    previous: Option<Arc<Mutex<HashCodeMap>>>,
+
+    /// Prevents sending the guard to another thread.
+    _marker: PhantomData<*const ()>,
+}
+
+impl Drop for HashCodeMapGuard {
+    fn drop(&mut self) {
+        SCOPED.set(self.previous.take());
+    }
+}
+
+/// The annotations of a label.
+#[derive(Debug, Clone, Default, PartialEq)]
+pub struct LabelInfo {
+    /// The kind of data the label identifies.
+    pub category: Option<LabelCategory>,
+
+    /// The English translation of the label.
+    pub translation: Option<String>,
+
+    /// The supplementary remarks about the label.
+    pub notes: Option<String>,
+}
+
+/// A kind of data identified by a label.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum LabelCategory {
+    /// The name of a galaxy.
+    Galaxy,
+
+    /// The key of a `GameEventFlag`.
+    EventFlag,
+
+    /// The key of a `GameEventValue`.
+    EventValue,
+}
+
+impl FromStr for LabelCategory {
+    type Err = ParseLabelError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s {
+            "galaxy" => Ok(Self::Galaxy),
+            "event_flag" => Ok(Self::EventFlag),
+            "event_value" => Ok(Self::EventValue),
+            _ => Err(ParseLabelError::UnknownCategory(s.into())),
+        }
     }
 }
 
+/// A group of labels whose hashes are equal when truncated to the same bit width.
+#[derive(Debug, Clone, PartialEq)]
+pub struct LabelCollision {
+    /// The number of least significant bits compared.
+    pub width: u32,
+
+    /// The shared truncated hash.
+    pub hash: HashCode,
+
+    /// The sorted collection of labels sharing the truncated hash.
+    pub labels: Vec<String>,
+}
+
 /// An error returned from converting a collection of labels.
 #[derive(Debug, Error)]
 pub enum ParseLabelError {
-    /// An error occurred while inserting labels into the map using an incompatible character encoding.
-    #[error("the requested character encoding does not match the current character encoding")]
-    InconsistentEncoding,
-
     /// An error occurred while performing I/O operations.
     #[error(transparent)]
     Io(#[from] io::Error),
@@ -161,11 +450,15 @@ pub enum ParseLabelError {
     /// An error occurred while encoding from UTF-8 to Shift JIS.
     #[error("label contains Shift JIS errors")]
     EncodeShiftJis,
+
+    /// An error occurred while parsing an unrecognized label category.
+    #[error("the label category `{0}` is not recognized")]
+    UnknownCategory(String),
 }
 
 /// A character encoding for labels prior to hashing.
-#[derive(Debug, Clone, Copy, PartialEq)]
-enum Encoding {
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Encoding {
     /// Shift JIS
     ShiftJis,
 
@@ -173,18 +466,76 @@ enum Encoding {
     Utf8,
 }
 
-/// Creates a hash-label pair from a string converted to Shift JIS.
-fn encode_shift_jis(label: String) -> Result<(HashCode, String), ParseLabelError> {
-    let (encoded, _, is_error) = SHIFT_JIS.encode(&label);
+impl Encoding {
+    /// Every supported character encoding.
+    pub const ALL: [Self; 2] = [Self::ShiftJis, Self::Utf8];
+
+    /// Hashes a label after converting it to the character encoding.
+    pub fn hash(self, label: &str) -> Result<HashCode, ParseLabelError> {
+        match self {
+            Self::ShiftJis => Ok(HashCode::from(self.encode(label)?.as_slice())),
+            Self::Utf8 => Ok(HashCode::from(label)),
+        }
+    }
+
+    /// Converts a label into the bytes to be hashed.
+    pub fn encode(self, label: &str) -> Result<Vec<u8>, ParseLabelError> {
+        match self {
+            Self::ShiftJis => {
+                let (encoded, _, is_error) = SHIFT_JIS.encode(label);
+
+                if is_error {
+                    return Err(ParseLabelError::EncodeShiftJis);
+                }
+
+                Ok(encoded.into_owned())
+            }
+            Self::Utf8 => Ok(label.as_bytes().to_vec()),
+        }
+    }
+}
+
+/// Returns the bitmask for the least significant bits of a hash.
+pub(super) const fn truncation_mask(width: u32) -> u32 {
+    u32::MAX >> (u32::BITS - width)
+}
 
-    if is_error {
-        return Err(ParseLabelError::EncodeShiftJis);
+/// Splits a line into a label and its tab-separated annotations, if any.
+fn parse_line(line: String) -> Result<(String, Option<LabelInfo>), ParseLabelError> {
+    if !line.contains('\t') {
+        return Ok((line, None));
     }
 
-    Ok((HashCode::from(encoded.as_ref()), label))
+    let mut columns = line.split('\t');
+    let label = columns.next().unwrap_or_default().to_string();
+    let mut column = || columns.next().filter(|c| !c.is_empty()).map(String::from);
+
+    let category = column().map(|c| c.parse()).transpose()?;
+    let translation = column();
+    let notes = column();
+    let info = LabelInfo {
+        category,
+        translation,
+        notes,
+    };
+
+    Ok((label, Some(info)))
 }
 
-/// Creates a hash-label pair from a string.
-fn encode_utf8(label: String) -> Result<(HashCode, String), ParseLabelError> {
-    Ok((HashCode::from(&label), label))
+/// Hashes a label under each of the given encodings.
+///
+/// If more than one encoding is given, encodings unable to represent the label
+/// are skipped.
+fn hash_label(
+    label: &str,
+    encodings: &[Encoding],
+) -> Result<Vec<(Encoding, HashCode)>, ParseLabelError> {
+    if let [encoding] = encodings {
+        return Ok(vec![(*encoding, encoding.hash(label)?)]);
+    }
+
+    Ok(encodings
+        .iter()
+        .filter_map(|e| Some((*e, e.hash(label).ok()?)))
+        .collect())
 }
@@ -0,0 +1,270 @@
+use std::{
+    collections::{HashMap, HashSet},
+    fs,
+    path::Path,
+    slice,
+};
+
+use thiserror::Error;
+
+use super::{Encoding, HashCode, ParseLabelError, map::truncation_mask};
+
+/// A generator of candidate labels for recovering the original label of a hash.
+///
+/// Candidates are built from templates, where each `{name}` placeholder is
+/// substituted with every token of the dictionary of the same name.
+#[derive(Debug)]
+pub struct LabelRecovery {
+    /// The character encoding for labels prior to hashing.
+    encoding: Encoding,
+
+    /// The collection of token lists, keyed by name.
+    dictionaries: HashMap<String, Vec<Token>>,
+
+    /// The collection of parsed templates.
+    templates: Vec<Vec<Segment>>,
+}
+
+impl LabelRecovery {
+    /// Creates a new, empty `LabelRecovery` hashing labels under the given encoding.
+    pub fn new(encoding: Encoding) -> Self {
+        Self {
+            encoding,
+            dictionaries: HashMap::new(),
+            templates: Vec::new(),
+        }
+    }
+
+    /// Reads templates and dictionaries from a file.
+    ///
+    /// Each line preceding the first `[name]` header is a template. Each line
+    /// following a header is a token of the dictionary with that name. Empty
+    /// lines are ignored.
+    pub fn read<P: AsRef<Path>>(path: P, encoding: Encoding) -> Result<Self, RecoverLabelError> {
+        let mut recovery = Self::new(encoding);
+        let mut dictionary = None;
+
+        for line in fs::read_to_string(path)
+            .map_err(ParseLabelError::from)?
+            .lines()
+        {
+            if line.is_empty() {
+                continue;
+            }
+
+            if let Some(name) = line.strip_prefix('[').and_then(|l| l.strip_suffix(']')) {
+                recovery.dictionaries.entry(name.to_string()).or_default();
+                dictionary = Some(name.to_string());
+                continue;
+            }
+
+            match &dictionary {
+                Some(name) => recovery.add_tokens(name, [line])?,
+                None => recovery.add_template(line)?,
+            }
+        }
+
+        Ok(recovery)
+    }
+
+    /// Appends tokens to the dictionary with the given name, creating it if absent.
+    pub fn add_tokens<I, S>(&mut self, name: &str, tokens: I) -> Result<(), RecoverLabelError>
+    where
+        I: IntoIterator<Item = S>,
+        S: Into<String>,
+    {
+        let tokens = tokens
+            .into_iter()
+            .map(|t| Token::new(t.into(), self.encoding))
+            .collect::<Result<Vec<_>, _>>()?;
+
+        self.dictionaries
+            .entry(name.to_string())
+            .or_default()
+            .extend(tokens);
+
+        Ok(())
+    }
+
+    /// Parses and appends a template, where each `{name}` placeholder refers to a dictionary.
+    pub fn add_template(&mut self, template: &str) -> Result<(), RecoverLabelError> {
+        let mut segments = Vec::new();
+        let mut rest = template;
+
+        while let Some(start) = rest.find('{') {
+            let Some(end) = rest[start..].find('}').map(|i| start + i) else {
+                return Err(RecoverLabelError::UnclosedPlaceholder(template.into()));
+            };
+
+            if start > 0 {
+                let token = Token::new(rest[..start].into(), self.encoding)?;
+
+                segments.push(Segment::Literal(token));
+            }
+
+            segments.push(Segment::Placeholder(rest[start + 1..end].into()));
+            rest = &rest[end + 1..];
+        }
+
+        if !rest.is_empty() {
+            segments.push(Segment::Literal(Token::new(rest.into(), self.encoding)?));
+        }
+
+        self.templates.push(segments);
+
+        Ok(())
+    }
+
+    /// Returns every candidate label corresponding to the hash.
+    ///
+    /// If a width is given, only the least significant bits of the hash are
+    /// compared. Shorter widths are more likely to produce false positives.
+    pub fn recover(
+        &self,
+        hash: HashCode,
+        width: Option<u32>,
+    ) -> Result<Vec<String>, RecoverLabelError> {
+        let recovered = self.recover_all([(hash, width)])?;
+
+        Ok(recovered.into_iter().map(|r| r.label).collect())
+    }
+
+    /// Returns every candidate label corresponding to any of the hashes, sorted by width, hash and label.
+    ///
+    /// Each template is expanded once regardless of the number of hashes.
+    pub fn recover_all<I>(&self, targets: I) -> Result<Vec<RecoveredLabel>, RecoverLabelError>
+    where
+        I: IntoIterator<Item = (HashCode, Option<u32>)>,
+    {
+        let mut truncated = HashMap::<Option<u32>, HashSet<u32>>::new();
+
+        for (hash, width) in targets {
+            truncated
+                .entry(width)
+                .or_default()
+                .insert(hash.into_raw() & width.map_or(u32::MAX, truncation_mask));
+        }
+
+        let mut recovered = Vec::new();
+
+        for template in &self.templates {
+            let segments = template
+                .iter()
+                .map(|segment| match segment {
+                    Segment::Literal(token) => Ok(slice::from_ref(token)),
+                    Segment::Placeholder(name) => self
+                        .dictionaries
+                        .get(name)
+                        .map(Vec::as_slice)
+                        .ok_or_else(|| RecoverLabelError::UnknownDictionary(name.clone())),
+                })
+                .collect::<Result<Vec<_>, _>>()?;
+
+            expand(
+                &segments,
+                HashCode::default(),
+                &mut String::new(),
+                &truncated,
+                &mut recovered,
+            );
+        }
+
+        recovered.sort_unstable_by(|a, b| {
+            (a.width, a.hash.into_raw(), &a.label).cmp(&(b.width, b.hash.into_raw(), &b.label))
+        });
+        recovered.dedup();
+
+        Ok(recovered)
+    }
+}
+
+/// A candidate label whose hash matches a requested hash.
+#[derive(Debug, Clone, PartialEq)]
+pub struct RecoveredLabel {
+    /// The number of least significant bits compared, or `None` if the full hash was compared.
+    pub width: Option<u32>,
+
+    /// The requested hash, truncated to the width.
+    pub hash: HashCode,
+
+    /// The candidate label.
+    pub label: String,
+}
+
+/// An error returned from recovering the original label of a hash.
+#[derive(Debug, Error)]
+pub enum RecoverLabelError {
+    /// An error occurred while parsing a template with a placeholder missing its closing brace.
+    #[error("the template `{0}` contains an unclosed placeholder")]
+    UnclosedPlaceholder(String),
+
+    /// An error occurred while expanding a template referring to a missing dictionary.
+    #[error("the template refers to the unknown dictionary `{0}`")]
+    UnknownDictionary(String),
+
+    /// An error occurred while reading or encoding a label.
+    #[error(transparent)]
+    ParseLabel(#[from] ParseLabelError),
+}
+
+/// A fragment of a label alongside its encoded bytes.
+#[derive(Debug)]
+struct Token {
+    /// The original fragment.
+    label: String,
+
+    /// The fragment converted to the configured character encoding.
+    bytes: Vec<u8>,
+}
+
+impl Token {
+    /// Creates a new `Token` by converting the fragment to the character encoding.
+    fn new(label: String, encoding: Encoding) -> Result<Self, ParseLabelError> {
+        let bytes = encoding.encode(&label)?;
+
+        Ok(Self { label, bytes })
+    }
+}
+
+/// A part of a template.
+#[derive(Debug)]
+enum Segment {
+    /// A fixed fragment.
+    Literal(Token),
+
+    /// A reference to a dictionary by name.
+    Placeholder(String),
+}
+
+/// Substitutes each remaining segment in turn, collecting every complete label matching a hash.
+fn expand(
+    segments: &[&[Token]],
+    hash: HashCode,
+    label: &mut String,
+    truncated: &HashMap<Option<u32>, HashSet<u32>>,
+    recovered: &mut Vec<RecoveredLabel>,
+) {
+    let Some((tokens, rest)) = segments.split_first() else {
+        for (&width, hashes) in truncated {
+            let hash = hash.into_raw() & width.map_or(u32::MAX, truncation_mask);
+
+            if hashes.contains(&hash) {
+                recovered.push(RecoveredLabel {
+                    width,
+                    hash: HashCode::from_raw(hash),
+                    label: label.clone(),
+                });
+            }
+        }
+
+        return;
+    };
+
+    for token in *tokens {
+        let len = label.len();
+
+        label.push_str(&token.label);
+        expand(rest, hash.update(&token.bytes), label, truncated, recovered);
+        label.truncate(len);
+    }
+}
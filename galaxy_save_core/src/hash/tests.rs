@@ -1,3 +1,7 @@
+use std::sync::Arc;
+
+use parking_lot::Mutex;
+
 use super::*;
 
 #[test]
@@ -66,3 +70,283 @@ fn from_japanese_utf8() {
     let hash = HashCode::from("アイスマリオ初変身");
     assert_eq!(hash, HashCode::from_raw(0x5F3F0962));
 }
+
+#[test]
+fn label_of_truncated() {
+    let mut map = HashCodeMap::default();
+    map.extend_utf8(["Flag10".to_string(), "Flag2155".to_string()])
+        .unwrap();
+
+    // Both hashes equal 0x46AB when truncated to 15 bits, but not when truncated to 16 bits.
+    let hash = HashCode::from_raw(0x7D92C6AB);
+    assert_eq!(map.label_of(hash, None).unwrap(), "Flag10");
+    assert_eq!(map.label_of(hash, Some(u16::BITS)).unwrap(), "Flag10");
+    assert_eq!(map.label_of(hash, Some(u16::BITS - 1)), None);
+    assert_eq!(
+        map.labels_of(hash, Some(u16::BITS - 1)),
+        ["Flag10", "Flag2155"]
+    );
+}
+
+#[test]
+fn collisions() {
+    let mut map = HashCodeMap::default();
+    map.extend_utf8(["Flag10".to_string(), "Flag2155".to_string()])
+        .unwrap();
+    map.extend_utf8(["Flag3486".to_string(), "Flag11".to_string()])
+        .unwrap();
+
+    let collisions = map.collisions();
+    assert_eq!(collisions.len(), 2);
+
+    assert_eq!(collisions[0].width, 15);
+    assert_eq!(collisions[0].hash, HashCode::from_raw(0x46AB));
+    assert_eq!(collisions[0].labels, ["Flag10", "Flag2155", "Flag3486"]);
+
+    assert_eq!(collisions[1].width, 16);
+    assert_eq!(collisions[1].hash, HashCode::from_raw(0xC6AB));
+    assert_eq!(collisions[1].labels, ["Flag10", "Flag3486"]);
+
+    map.extend_utf8(["Flag10".to_string()]).unwrap();
+    assert_eq!(map.collisions(), collisions);
+
+    map.clear();
+    assert!(map.collisions().is_empty());
+}
+
+#[test]
+fn enter_scoped_map() {
+    let shift_jis = Arc::new(Mutex::new(HashCodeMap::default()));
+    shift_jis
+        .lock()
+        .extend_shift_jis(["ゲーム".to_string()])
+        .unwrap();
+
+    let utf8 = Arc::new(Mutex::new(HashCodeMap::default()));
+    utf8.lock().extend_utf8(["ゲーム".to_string()]).unwrap();
+
+    let hash_shift_jis = shift_jis.lock().hash_of("ゲーム").unwrap();
+    let hash_utf8 = utf8.lock().hash_of("ゲーム").unwrap();
+    assert_ne!(hash_shift_jis, hash_utf8);
+
+    {
+        let _guard = HashCodeMap::enter(shift_jis);
+        assert_eq!(HashCode::from_label("ゲーム").unwrap(), hash_shift_jis);
+        assert_eq!(hash_shift_jis.to_label(None), "ゲーム");
+
+        {
+            let _guard = HashCodeMap::enter(utf8);
+            assert_eq!(HashCode::from_label("ゲーム").unwrap(), hash_utf8);
+            assert_eq!(
+                hash_shift_jis.to_label(None),
+                format!("{:#X}", hash_shift_jis.into_raw())
+            );
+        }
+
+        assert_eq!(HashCode::from_label("ゲーム").unwrap(), hash_shift_jis);
+    }
+
+    assert!(Arc::ptr_eq(&HashCodeMap::current(), &HashCodeMap::get()));
+}
+
+#[test]
+fn recover_labels() {
+    let mut recovery = LabelRecovery::new(Encoding::Utf8);
+    recovery
+        .add_tokens("n", (0..=4000).map(|n| n.to_string()))
+        .unwrap();
+    recovery.add_template("Flag{n}").unwrap();
+
+    let hash = HashCode::from("Flag10");
+    assert_eq!(recovery.recover(hash, None).unwrap(), ["Flag10"]);
+    assert_eq!(
+        recovery.recover(hash, Some(15)).unwrap(),
+        ["Flag10", "Flag2155", "Flag3486"]
+    );
+    assert_eq!(
+        recovery.recover(hash, Some(16)).unwrap(),
+        ["Flag10", "Flag3486"]
+    );
+
+    let recovered = recovery
+        .recover_all([(hash, Some(16)), (HashCode::from("Flag11"), None)])
+        .unwrap();
+    assert_eq!(recovered.len(), 3);
+    assert_eq!(recovered[0].width, None);
+    assert_eq!(recovered[0].label, "Flag11");
+    assert_eq!(recovered[1].hash, HashCode::from_raw(0xC6AB));
+
+    recovery.add_template("{missing}").unwrap();
+    assert!(matches!(
+        recovery.recover(hash, None),
+        Err(RecoverLabelError::UnknownDictionary(_))
+    ));
+    assert!(matches!(
+        recovery.add_template("Flag{n"),
+        Err(RecoverLabelError::UnclosedPlaceholder(_))
+    ));
+}
+
+#[test]
+fn recover_labels_shift_jis() {
+    let mut recovery = LabelRecovery::new(Encoding::ShiftJis);
+    recovery.add_tokens("word", ["ゲーム", "データ"]).unwrap();
+    recovery.add_template("{word}{word}").unwrap();
+
+    let hash = HashCode::from(
+        Encoding::ShiftJis
+            .encode("データゲーム")
+            .unwrap()
+            .as_slice(),
+    );
+    assert_eq!(recovery.recover(hash, None).unwrap(), ["データゲーム"]);
+}
+
+#[test]
+fn read_annotated() {
+    let path = std::env::temp_dir().join("galaxy_save_core_read_annotated.tsv");
+    std::fs::write(
+        &path,
+        "Flag10\tevent_flag\tFlag Ten\tA note.\nFlag11\nFlag12\t\tFlag Twelve\n",
+    )
+    .unwrap();
+
+    let mut map = HashCodeMap::default();
+    map.read_utf8(&path).unwrap();
+
+    let hash = HashCode::from("Flag10");
+    let info = map.info_of(hash, Some(15)).unwrap();
+    assert_eq!(info.category, Some(LabelCategory::EventFlag));
+    assert_eq!(info.translation.as_deref(), Some("Flag Ten"));
+    assert_eq!(info.notes.as_deref(), Some("A note."));
+    assert_eq!(map.label_of(hash, None).unwrap(), "Flag10");
+
+    assert!(map.info_of(HashCode::from("Flag11"), None).is_none());
+    assert_eq!(
+        map.info_of(HashCode::from("Flag12"), None)
+            .unwrap()
+            .category,
+        None
+    );
+
+    assert!(map.translation_of(hash, None).is_none());
+    map.set_translated(true);
+    assert_eq!(map.translation_of(hash, None).unwrap(), "Flag Ten");
+
+    std::fs::write(&path, "Flag10\tunknown\n").unwrap();
+    assert!(matches!(
+        HashCodeMap::default().read_utf8(&path),
+        Err(ParseLabelError::UnknownCategory(_))
+    ));
+
+    std::fs::remove_file(path).unwrap();
+}
+
+#[test]
+fn hash_of_every_encoding() {
+    let mut map = HashCodeMap::default();
+    map.extend(["ゲーム".to_string(), "Flag10".to_string()])
+        .unwrap();
+    map.extend_utf8(["データ".to_string()]).unwrap();
+
+    let hash_shift_jis = Encoding::ShiftJis.hash("ゲーム").unwrap();
+    let hash_utf8 = Encoding::Utf8.hash("ゲーム").unwrap();
+    assert_eq!(map.label_of(hash_shift_jis, None).unwrap(), "ゲーム");
+    assert_eq!(map.label_of(hash_utf8, None).unwrap(), "ゲーム");
+    assert_eq!(
+        map.label_of(HashCode::from("Flag10"), Some(15)).unwrap(),
+        "Flag10"
+    );
+
+    map.set_encoding(Some(Encoding::ShiftJis));
+    assert_eq!(map.hash_of("ゲーム"), Some(hash_shift_jis));
+    assert_eq!(
+        map.hash_of("データ"),
+        Some(Encoding::ShiftJis.hash("データ").unwrap())
+    );
+
+    map.set_encoding(Some(Encoding::Utf8));
+    assert_eq!(map.hash_of("ゲーム"), Some(hash_utf8));
+    assert!(map.collisions().is_empty());
+}
+
+#[test]
+fn from_shift_jis() {
+    let hash = HashCode::from_shift_jis("ハチマリオ初変身").unwrap();
+    assert_eq!(hash, HashCode::from_raw(0x878D7ABA));
+
+    let hash = HashCode16::from_shift_jis("テレサマリオ初変身").unwrap();
+    assert_eq!(hash, HashCode16::from_raw(0x8C8E));
+
+    let hash = HashCode::for_label("ハチマリオ初変身", Platform::ShieldTv).unwrap();
+    assert_eq!(hash, HashCode::from_raw(0x878D7ABA));
+
+    let hash = HashCode::for_label("ハチマリオ初変身", Platform::Switch).unwrap();
+    assert_eq!(hash, HashCode::from_raw(0x7BB6DD3C));
+}
+
+#[test]
+fn hash_key_encoding() {
+    let key = HashKey::from("ハチマリオ初変身");
+
+    assert_eq!(
+        key.hash_code(Platform::Wii.encoding()).unwrap(),
+        HashCode::from_raw(0x878D7ABA)
+    );
+    assert_eq!(
+        key.hash_code(Platform::Switch.encoding()).unwrap(),
+        HashCode::from_raw(0x7BB6DD3C)
+    );
+    assert_eq!(
+        key.hash_codes(),
+        [
+            HashCode::from_raw(0x878D7ABA),
+            HashCode::from_raw(0x7BB6DD3C)
+        ]
+    );
+}
+
+#[test]
+fn hash_key_ignores_map_encoding() {
+    let mut map = HashCodeMap::default();
+    map.set_encoding(Some(Encoding::Utf8));
+
+    let _guard = HashCodeMap::enter(Arc::new(Mutex::new(map)));
+    let key = HashKey::from("ハチマリオ初変身");
+
+    assert_eq!(key.hash_codes()[0], HashCode::from_raw(0x878D7ABA));
+}
+
+#[test]
+fn hash_key_codes() {
+    let hash = HashCode::from_raw(0x1234);
+
+    assert_eq!(HashKey::from(hash).hash_codes(), [hash]);
+    assert_eq!(HashKey::from("Flag").hash_codes(), [HashCode::from("Flag")]);
+
+    // Characters outside of Shift JIS are only hashed as UTF-8.
+    assert_eq!(HashKey::from("🍄").hash_codes(), [HashCode::from("🍄")]);
+}
+
+#[test]
+fn extend_preferred_encoding() {
+    // The UTF-8 hash of the first label shares its 16 least significant bits
+    // with the hash of the second label.
+    let labels = ["ハチマリオ初変身".to_string(), "Flagfkm".to_string()];
+
+    let mut map = HashCodeMap::default();
+    map.set_encoding(Some(Encoding::ShiftJis));
+    map.extend(labels.clone()).unwrap();
+
+    let hash = Encoding::ShiftJis.hash("ハチマリオ初変身").unwrap();
+    assert_eq!(map.label_of(hash, Some(16)).unwrap(), "ハチマリオ初変身");
+    assert_eq!(
+        map.label_of(HashCode::from("Flagfkm"), Some(16)).unwrap(),
+        "Flagfkm"
+    );
+    assert!(map.collisions().is_empty());
+
+    let mut map = HashCodeMap::default();
+    map.extend(labels).unwrap();
+    assert!(map.label_of(HashCode::from("Flagfkm"), Some(16)).is_none());
+}
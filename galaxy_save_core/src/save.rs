@@ -2,9 +2,42 @@
 
 use std::io;
 
+use binrw::Endian;
 use thiserror::Error;
 
-use crate::mem::Checksum;
+use crate::{hash::Encoding, mem::Checksum};
+
+/// A console the save file originates from or is intended for.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub enum Platform {
+    /// Nintendo Wii
+    #[default]
+    Wii,
+
+    /// NVIDIA Shield TV
+    ShieldTv,
+
+    /// Nintendo Switch
+    Switch,
+}
+
+impl Platform {
+    /// Returns the byte order of the save file.
+    pub const fn endian(self) -> Endian {
+        match self {
+            Self::Wii | Self::ShieldTv => Endian::Big,
+            Self::Switch => Endian::Little,
+        }
+    }
+
+    /// Returns the character encoding for labels prior to hashing.
+    pub const fn encoding(self) -> Encoding {
+        match self {
+            Self::Wii | Self::ShieldTv => Encoding::ShiftJis,
+            Self::Switch => Encoding::Utf8,
+        }
+    }
+}
 
 /// A trait for standardizing save file header implementation.
 pub trait SaveFileHeader {
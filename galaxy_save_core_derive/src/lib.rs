@@ -21,6 +21,7 @@ fn expand_header_serializer_impl(input: syn::DeriveInput) -> TokenStream {
     let attr_set = receiver.attr_token_stream();
     let attr_set_num = attr_set.len();
     let data_size = receiver.data_size_token_stream();
+    let dyn_attr_set = receiver.dyn_attr_token_stream();
 
     quote! {
         impl HeaderSerializer for #ident {
@@ -38,6 +39,26 @@ fn expand_header_serializer_impl(input: syn::DeriveInput) -> TokenStream {
             fn data_size() -> usize {
                 #(#data_size)+*
             }
+
+            fn dyn_header_serializer(&self) -> galaxy_save_core::bin::BinaryDataContentHeaderSerializer<#ident> {
+                let mut attributes = Vec::new();
+                let mut data_size = 0;
+
+                #(#dyn_attr_set)*
+
+                galaxy_save_core::bin::BinaryDataContentHeaderSerializer::new(attributes, data_size)
+            }
+
+            fn dyn_header_size(&self) -> usize {
+                size_of::<u16>()
+                    + size_of::<u16>()
+                    + self.dyn_header_serializer().attributes.len()
+                    * size_of::<galaxy_save_core::bin::BinaryDataContentAttribute>()
+            }
+
+            fn dyn_data_size(&self) -> usize {
+                self.dyn_header_serializer().data_size()
+            }
         }
     }
 }
@@ -20,32 +20,40 @@ impl HeaderSerializerInput {
     }
 
     /// Creates a collection of token trees containing field descriptor expressions.
+    ///
+    /// Optional fields are not considered.
     pub fn attr_token_stream(&self) -> Vec<TokenStream> {
-        let ast::Data::Struct(ref fields) = self.data else {
-            panic!("receiver type should be a struct containing named fields");
-        };
+        let mut offset = vec![quote! { 0 }];
 
-        let mut offset = Vec::with_capacity(fields.len());
-        offset.push(quote! { 0 });
-
-        fields
-            .iter()
-            .filter(|f| !f.skip)
+        self.fields()
+            .filter(|f| !f.is_optional())
             .map(|f| f.attr_token_stream(&mut offset))
             .collect()
     }
 
     /// Creates a collection of token trees containing type size expressions.
+    ///
+    /// Optional fields are not considered.
     pub fn data_size_token_stream(&self) -> Vec<TokenStream> {
+        self.fields()
+            .filter(|f| !f.is_optional())
+            .map(|f| f.data_size_token_stream())
+            .collect()
+    }
+
+    /// Creates a collection of token trees containing statements which push
+    /// the field descriptors of the fields present in `self` to `attributes`.
+    pub fn dyn_attr_token_stream(&self) -> Vec<TokenStream> {
+        self.fields().map(|f| f.dyn_attr_token_stream()).collect()
+    }
+
+    /// Returns an iterator over the considered fields.
+    fn fields(&self) -> impl Iterator<Item = &HeaderSerializerField> {
         let ast::Data::Struct(ref fields) = self.data else {
             panic!("receiver type should be a struct containing named fields");
         };
 
-        fields
-            .iter()
-            .filter(|f| !f.skip)
-            .map(|f| f.data_size_token_stream())
-            .collect()
+        fields.iter().filter(|f| !f.skip)
     }
 }
 
@@ -53,6 +61,9 @@ impl HeaderSerializerInput {
 #[derive(Debug, FromField)]
 #[darling(attributes(header_serializer))]
 struct HeaderSerializerField {
+    /// The name of the field.
+    ident: Option<syn::Ident>,
+
     /// The data type of the field.
     ty: syn::Type,
 
@@ -92,9 +103,61 @@ impl HeaderSerializerField {
         }
     }
 
-    /// Returns the data type of the field as a token tree.
+    /// Creates a token tree containing the statement which pushes the field
+    /// descriptor to `attributes` if the field is present in `self`.
+    fn dyn_attr_token_stream(&self) -> TokenStream {
+        let key = self.name.as_ref().unwrap();
+        let ty = self.ty();
+        let push = quote! {
+            attributes.push(galaxy_save_core::bin::BinaryDataContentAttribute {
+                key: galaxy_save_core::hash::HashCode16::from(#key),
+                offset: data_size as u16,
+            });
+            data_size += size_of::<#ty>();
+        };
+
+        if self.is_optional() {
+            let ident = self.ident.as_ref().unwrap();
+
+            quote! {
+                if self.#ident.is_some() {
+                    #push
+                }
+            }
+        } else {
+            push
+        }
+    }
+
+    /// Returns `true` if the field is an `Option`, which is only serialized if present.
+    fn is_optional(&self) -> bool {
+        self.option_inner_ty().is_some()
+    }
+
+    /// Returns the type wrapped by the field's `Option` type, if any.
+    fn option_inner_ty(&self) -> Option<&syn::Type> {
+        let syn::Type::Path(ty) = &self.ty else {
+            return None;
+        };
+        let segment = ty.path.segments.last()?;
+
+        if segment.ident != "Option" {
+            return None;
+        }
+
+        let syn::PathArguments::AngleBracketed(args) = &segment.arguments else {
+            return None;
+        };
+
+        match args.args.first()? {
+            syn::GenericArgument::Type(ty) => Some(ty),
+            _ => None,
+        }
+    }
+
+    /// Returns the serialized data type of the field as a token tree.
     fn ty(&self) -> TokenStream {
-        match &self.ty {
+        match self.option_inner_ty().unwrap_or(&self.ty) {
             syn::Type::Array(ty) => ty.to_token_stream(),
             syn::Type::Path(ty) => ty.to_token_stream(),
             _ => panic!("field type should be a primitive"),
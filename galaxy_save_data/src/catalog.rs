@@ -0,0 +1,293 @@
+//! Catalogs of known galaxies and their missions, mirroring the stage list of
+//! the game.
+
+use std::borrow::Cow;
+
+use galaxy_save_core::hash::{HashCode, HashCode16, HashKey};
+
+#[cfg(all(test, feature = "serde"))]
+mod tests;
+
+/// Defines the enumeration of known galaxies alongside their names, domes and missions.
+macro_rules! galaxies {
+    (
+        $(#[$meta:meta])*
+        $vis:vis enum $name:ident {
+            $(
+                $variant:ident = ($display:literal, $dome:expr, [$($mission:ident),*]),
+            )*
+        }
+    ) => {
+        $(#[$meta])*
+        #[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+        $vis enum $name {
+            $(
+                #[doc = concat!("The ", $display, ".")]
+                $variant,
+            )*
+        }
+
+        impl $name {
+            /// Every known galaxy, in the order of the stage list.
+            pub const ALL: &[Self] = &[$(Self::$variant,)*];
+
+            /// Returns the internal name of the galaxy.
+            pub const fn label(self) -> &'static str {
+                match self {
+                    $(Self::$variant => stringify!($variant),)*
+                }
+            }
+
+            /// Returns the English name of the galaxy.
+            pub const fn name(self) -> &'static str {
+                match self {
+                    $(Self::$variant => $display,)*
+                }
+            }
+
+            /// Returns the dome of the Comet Observatory leading to the galaxy, if any.
+            pub const fn dome(self) -> Option<Dome> {
+                match self {
+                    $(Self::$variant => $dome,)*
+                }
+            }
+
+            /// Returns the kind of each mission, indexed by its bit in the Star flags.
+            pub const fn missions(self) -> &'static [MissionKind] {
+                match self {
+                    $(Self::$variant => &[$(MissionKind::$mission),*],)*
+                }
+            }
+        }
+    };
+}
+
+galaxies! {
+    /// A known galaxy.
+    pub enum Galaxy {
+        AstroGalaxy = ("Comet Observatory", None, []),
+        HeavensDoorGalaxy = ("Gateway Galaxy", None, [Normal, Normal]),
+        EggStarGalaxy = ("Good Egg Galaxy", Some(Dome::Terrace), [Normal, Normal, Normal, Hidden, Comet, PurpleComet]),
+        HoneyBeeKingdomGalaxy = ("Honeyhive Galaxy", Some(Dome::Terrace), [Normal, Normal, Normal, Hidden, Comet, PurpleComet]),
+        SurfingLv1Galaxy = ("Loopdeeloop Galaxy", Some(Dome::Terrace), [Normal]),
+        FlipPanelExGalaxy = ("Flipswitch Galaxy", Some(Dome::Terrace), [Normal]),
+        BeltConveyerExGalaxy = ("Sweet Sweet Galaxy", Some(Dome::Terrace), [Normal]),
+        TriLegLv1Galaxy = ("Bowser Jr.'s Robot Reactor", Some(Dome::Terrace), [Normal]),
+        StarDustGalaxy = ("Space Junk Galaxy", Some(Dome::Fountain), [Normal, Normal, Normal, Hidden, Comet, PurpleComet]),
+        TamakoroExLv1Galaxy = ("Rolling Green Galaxy", Some(Dome::Fountain), [Normal]),
+        BattleShipGalaxy = ("Battlerock Galaxy", Some(Dome::Fountain), [Normal, Normal, Normal, Comet, Hidden, Hidden, PurpleComet]),
+        BreakDownPlanetGalaxy = ("Hurry-Scurry Galaxy", Some(Dome::Fountain), [Normal]),
+        CocoonExGalaxy = ("Sling Pod Galaxy", Some(Dome::Fountain), [Normal]),
+        KoopaBattleVs1Galaxy = ("Bowser's Star Reactor", Some(Dome::Fountain), [Normal]),
+        HeavenlyBeachGalaxy = ("Beach Bowl Galaxy", Some(Dome::Kitchen), [Normal, Normal, Normal, Comet, Hidden, PurpleComet]),
+        CubeBubbleExLv1Galaxy = ("Bubble Breeze Galaxy", Some(Dome::Kitchen), [Normal]),
+        PhantomGalaxy = ("Ghostly Galaxy", Some(Dome::Kitchen), [Normal, Normal, Normal, Comet, Hidden, PurpleComet]),
+        OceanFloaterLandGalaxy = ("Buoy Base Galaxy", Some(Dome::Kitchen), [Normal, Hidden]),
+        TearDropGalaxy = ("Drip Drop Galaxy", Some(Dome::Kitchen), [Normal]),
+        TeresaMario2DGalaxy = ("Boo's Boneyard Galaxy", Some(Dome::Kitchen), [Normal]),
+        KoopaJrShipLv1Galaxy = ("Bowser Jr.'s Airship Armada", Some(Dome::Kitchen), [Normal]),
+        CosmosGardenGalaxy = ("Gusty Garden Galaxy", Some(Dome::Bedroom), [Normal, Normal, Normal, Comet, PurpleComet, Hidden]),
+        IceVolcanoGalaxy = ("Freezeflame Galaxy", Some(Dome::Bedroom), [Normal, Normal, Normal, Comet, PurpleComet, Hidden]),
+        HoneyBeeExGalaxy = ("Honeyclimb Galaxy", Some(Dome::Bedroom), [Normal]),
+        SandClockGalaxy = ("Dusty Dune Galaxy", Some(Dome::Bedroom), [Normal, Normal, Normal, Comet, PurpleComet, Hidden, Hidden]),
+        SnowCapsuleGalaxy = ("Snow Cap Galaxy", Some(Dome::Bedroom), [Normal]),
+        KoopaBattleVs2Galaxy = ("Bowser's Dark Matter Plant", Some(Dome::Bedroom), [Normal]),
+        ReverseKingdomGalaxy = ("Gold Leaf Galaxy", Some(Dome::EngineRoom), [Normal, Normal, Normal, Comet, PurpleComet, Hidden]),
+        OceanRingGalaxy = ("Sea Slide Galaxy", Some(Dome::EngineRoom), [Normal, Normal, Normal, Comet, Hidden, PurpleComet]),
+        SkullSharkGalaxy = ("Bonefin Galaxy", Some(Dome::EngineRoom), [Normal]),
+        FactoryGalaxy = ("Toy Time Galaxy", Some(Dome::EngineRoom), [Normal, Normal, Normal, Comet, PurpleComet, Hidden]),
+        TransformationExGalaxy = ("Sand Spiral Galaxy", Some(Dome::EngineRoom), [Normal]),
+        FloaterOtaKingGalaxy = ("Bowser Jr.'s Lava Reactor", Some(Dome::EngineRoom), [Normal]),
+        OceanPhantomCaveGalaxy = ("Deep Dark Galaxy", Some(Dome::Garden), [Normal, Normal, Normal, Comet, PurpleComet, Hidden]),
+        CannonFleetGalaxy = ("Dreadnought Galaxy", Some(Dome::Garden), [Normal, Normal, Normal, Comet, PurpleComet, Hidden]),
+        DarkRoomGalaxy = ("Matter Splatter Galaxy", Some(Dome::Garden), [Normal]),
+        HellProminenceGalaxy = ("Melty Molten Galaxy", Some(Dome::Garden), [Normal, Normal, Normal, Comet, PurpleComet, Hidden]),
+        FishTunnelGalaxy = ("Bigmouth Galaxy", Some(Dome::Garden), [Normal]),
+        KoopaBattleVs3Galaxy = ("Bowser's Galaxy Reactor", Some(Dome::Garden), [Normal]),
+        TamakoroExLv2Galaxy = ("Rolling Gizmo Galaxy", None, [Normal]),
+        SurfingLv2Galaxy = ("Loopdeeswoop Galaxy", None, [Normal]),
+        CubeBubbleExLv2Galaxy = ("Bubble Blast Galaxy", None, [Normal]),
+        PeachCastleFinalGalaxy = ("Grand Finale Galaxy", None, [Normal]),
+    }
+}
+
+impl Galaxy {
+    /// Returns the galaxy corresponding to the hashed internal name, if known.
+    pub fn from_hash_code(hash: impl Into<HashCode16>) -> Option<Self> {
+        let hash = hash.into();
+
+        Self::ALL.iter().copied().find(|g| hash == g.hash_code())
+    }
+
+    /// Returns the hash of the internal name.
+    pub fn hash_code(self) -> HashCode {
+        HashCode::from(self.label())
+    }
+
+    /// Returns the number of Stars that can be collected in the galaxy.
+    pub const fn star_num(self) -> usize {
+        self.missions().len()
+    }
+
+    /// Returns the index of the first mission of the kind, if any.
+    pub fn mission_index(self, kind: MissionKind) -> Option<usize> {
+        self.missions().iter().position(|m| *m == kind)
+    }
+}
+
+impl From<Galaxy> for HashCode {
+    fn from(galaxy: Galaxy) -> Self {
+        galaxy.hash_code()
+    }
+}
+
+impl From<Galaxy> for HashKey<'_> {
+    fn from(galaxy: Galaxy) -> Self {
+        Self::Label(Cow::Borrowed(galaxy.label()))
+    }
+}
+
+/// A dome of the Comet Observatory.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub enum Dome {
+    /// The Terrace.
+    Terrace,
+
+    /// The Fountain.
+    Fountain,
+
+    /// The Kitchen.
+    Kitchen,
+
+    /// The Bedroom.
+    Bedroom,
+
+    /// The Engine Room.
+    EngineRoom,
+
+    /// The Garden.
+    Garden,
+}
+
+impl Dome {
+    /// Every dome, in the order of opening.
+    pub const ALL: &[Self] = &[
+        Self::Terrace,
+        Self::Fountain,
+        Self::Kitchen,
+        Self::Bedroom,
+        Self::EngineRoom,
+        Self::Garden,
+    ];
+
+    /// Returns the English name of the dome.
+    pub const fn name(self) -> &'static str {
+        match self {
+            Self::Terrace => "Terrace",
+            Self::Fountain => "Fountain",
+            Self::Kitchen => "Kitchen",
+            Self::Bedroom => "Bedroom",
+            Self::EngineRoom => "Engine Room",
+            Self::Garden => "Garden",
+        }
+    }
+
+    /// Returns an iterator over the galaxies of the dome.
+    pub fn galaxies(self) -> impl Iterator<Item = Galaxy> {
+        Galaxy::ALL
+            .iter()
+            .copied()
+            .filter(move |g| g.dome() == Some(self))
+    }
+}
+
+/// The kind of a mission.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum MissionKind {
+    /// A mission selectable from the start.
+    Normal,
+
+    /// A mission whose Star is hidden within a selectable mission.
+    Hidden,
+
+    /// A mission available while a Prankster Comet is in orbit.
+    Comet,
+
+    /// A mission collecting the 100 purple coins, available after the Grand Finale.
+    PurpleComet,
+}
+
+impl MissionKind {
+    /// Returns the English name of the kind.
+    pub const fn name(self) -> &'static str {
+        match self {
+            Self::Normal => "Star",
+            Self::Hidden => "Hidden Star",
+            Self::Comet => "Prankster Comet",
+            Self::PurpleComet => "Purple Comet",
+        }
+    }
+}
+
+/// Serializes hashed galaxy names to their internal name, falling back to the
+/// catalog if the name is not found in the current label map.
+///
+/// If translations are enabled and the label map knows one for the name, the
+/// name is serialized alongside its translation.
+#[cfg(feature = "serde")]
+pub(crate) mod serde_galaxy_name {
+    use galaxy_save_core::hash::{HashCode, HashCode16, HashCodeMap};
+    use serde::{Deserialize, Deserializer, Serialize, Serializer};
+
+    use super::Galaxy;
+
+    /// The serialized form of a galaxy name accompanied by its translation.
+    #[derive(Serialize, Deserialize)]
+    #[serde(untagged)]
+    enum GalaxyName {
+        /// The internal name, or hexadecimal if unknown.
+        Label(String),
+
+        /// The internal name alongside its translation.
+        Translated { label: String, translation: String },
+    }
+
+    pub fn serialize<S>(hash: &HashCode16, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: Serializer,
+    {
+        let (label, translation) = {
+            let map = HashCodeMap::current();
+            let map = map.lock();
+            let hash = HashCode::from(*hash);
+
+            (
+                map.label_of(hash, Some(u16::BITS)).cloned(),
+                map.translation_of(hash, Some(u16::BITS)).cloned(),
+            )
+        };
+        let label = label
+            .or_else(|| Galaxy::from_hash_code(*hash).map(|g| g.label().into()))
+            .unwrap_or_else(|| hash.to_label());
+
+        match translation {
+            Some(translation) => GalaxyName::Translated { label, translation },
+            None => GalaxyName::Label(label),
+        }
+        .serialize(serializer)
+    }
+
+    pub fn deserialize<'de, D>(deserializer: D) -> Result<HashCode16, D::Error>
+    where
+        D: Deserializer<'de>,
+    {
+        let label = match GalaxyName::deserialize(deserializer)? {
+            GalaxyName::Label(label) | GalaxyName::Translated { label, .. } => label,
+        };
+
+        match Galaxy::ALL.iter().find(|g| g.label() == label) {
+            Some(galaxy) => Ok(galaxy.hash_code().into()),
+            None => HashCode16::from_label(&label).map_err(serde::de::Error::custom),
+        }
+    }
+}
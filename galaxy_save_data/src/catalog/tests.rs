@@ -0,0 +1,42 @@
+use std::sync::Arc;
+
+use galaxy_save_core::hash::HashCodeMap;
+use parking_lot::Mutex;
+use serde::{Deserialize, Serialize};
+
+use super::*;
+
+/// A galaxy name serialized as by galaxy states.
+#[derive(Serialize, Deserialize)]
+struct Name(#[serde(with = "serde_galaxy_name")] HashCode16);
+
+#[test]
+fn test_serde_galaxy_name_translation() {
+    let path = std::env::temp_dir().join("galaxy_save_data_serde_galaxy_name.tsv");
+    std::fs::write(&path, "EggStarGalaxy\tgalaxy\tGood Egg Galaxy\n").unwrap();
+
+    let map = Arc::new(Mutex::new(HashCodeMap::default()));
+    let _guard = HashCodeMap::enter(map.clone());
+    map.lock().read_utf8(&path).unwrap();
+    std::fs::remove_file(path).unwrap();
+
+    let hash = HashCode16::from(Galaxy::EggStarGalaxy.hash_code());
+    let json = serde_json::to_string(&Name(hash)).unwrap();
+    assert_eq!(json, r#""EggStarGalaxy""#);
+
+    map.lock().set_translated(true);
+
+    let json = serde_json::to_string(&Name(hash)).unwrap();
+    assert_eq!(
+        json,
+        r#"{"label":"EggStarGalaxy","translation":"Good Egg Galaxy"}"#
+    );
+    assert_eq!(serde_json::from_str::<Name>(&json).unwrap().0, hash);
+
+    map.lock().set_translated(false);
+    map.lock().clear();
+
+    let json = serde_json::to_string(&Name(hash)).unwrap();
+    assert_eq!(json, r#""EggStarGalaxy""#);
+    assert_eq!(serde_json::from_str::<Name>(&json).unwrap().0, hash);
+}
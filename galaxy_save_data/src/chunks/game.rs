@@ -1,11 +1,24 @@
 //! Data blocks storing gameplay data.
 
-use binrw::binrw;
-use galaxy_save_core::bin::{BinaryDataChunk, ChunkHolder};
+use std::collections::HashMap;
+
+use binrw::{BinResult, binrw};
+use galaxy_save_core::{
+    bin::{BinaryDataChunk, BinaryDataChunkHolder, ChunkHolder},
+    event::InsertEventError,
+    hash::{HashCode, HashKey, ParseHexError},
+    save::Platform,
+};
 
 #[cfg(feature = "serde")]
 use serde::{Deserialize, Serialize};
 
+use crate::{
+    completion::CompletionReport,
+    progression::{Milestone, ProgressError},
+    summary::GameDataSummary,
+};
+
 pub mod event_flag;
 pub mod event_value;
 pub mod galaxy;
@@ -67,3 +80,209 @@ impl ChunkHolder for GameDataChunk {
     const BUFFER_SIZE: usize = 0xF80;
     const VERSION: u8 = 1;
 }
+
+/// Accessors for the data blocks of a container of gameplay data.
+pub trait GameDataHolder {
+    /// Returns the collection of data blocks.
+    fn chunks(&self) -> &[GameDataChunk];
+
+    /// Returns the collection of data blocks, allowing modification of each.
+    fn chunks_mut(&mut self) -> &mut [GameDataChunk];
+
+    /// Returns the number of bytes of the data buffer left unused by the data blocks.
+    fn remaining_capacity(&self) -> BinResult<usize> {
+        GameDataChunk::remaining_capacity(self.chunks())
+    }
+
+    /// Returns a reference to the event flag state, if present.
+    fn event_flag(&self) -> Option<&GameEventFlagStorage> {
+        self.chunks().iter().find_map(|c| match c {
+            GameDataChunk::EventFlag(chunk) => Some(&chunk.inner),
+            _ => None,
+        })
+    }
+
+    /// Returns a mutable reference to the event flag state, if present.
+    fn event_flag_mut(&mut self) -> Option<&mut GameEventFlagStorage> {
+        self.chunks_mut().iter_mut().find_map(|c| match c {
+            GameDataChunk::EventFlag(chunk) => Some(&mut chunk.inner),
+            _ => None,
+        })
+    }
+
+    /// Inserts an event flag, returning the previous value if present.
+    ///
+    /// A new key is hashed under the character encoding of the platform, and
+    /// fails to insert if the data blocks would no longer fit within the data
+    /// buffer.
+    fn insert_event_flag<'a>(
+        &mut self,
+        key: impl Into<HashKey<'a>>,
+        value: bool,
+        platform: Platform,
+    ) -> Result<Option<bool>, InsertEventError> {
+        let key = key.into();
+        let event_flag = self.event_flag().ok_or(InsertEventError::MissingChunk)?;
+        let previous = event_flag.get(key.clone());
+
+        if previous.is_none() {
+            let hash = key.hash_code(platform.encoding())?;
+
+            GameDataChunk::check_capacity(self.chunks(), event_flag.growth(1))?;
+            self.event_flag_mut()
+                .ok_or(InsertEventError::MissingChunk)?
+                .push(hash, value);
+        } else {
+            self.event_flag_mut()
+                .ok_or(InsertEventError::MissingChunk)?
+                .set(key, value);
+        }
+
+        Ok(previous)
+    }
+
+    /// Returns a reference to the event value state, if present.
+    fn event_value(&self) -> Option<&GameEventValueStorage> {
+        self.chunks().iter().find_map(|c| match c {
+            GameDataChunk::EventValue(chunk) => Some(&chunk.inner),
+            _ => None,
+        })
+    }
+
+    /// Returns a mutable reference to the event value state, if present.
+    fn event_value_mut(&mut self) -> Option<&mut GameEventValueStorage> {
+        self.chunks_mut().iter_mut().find_map(|c| match c {
+            GameDataChunk::EventValue(chunk) => Some(&mut chunk.inner),
+            _ => None,
+        })
+    }
+
+    /// Inserts an event value, returning the previous value if present.
+    ///
+    /// A new key is hashed under the character encoding of the platform, and
+    /// fails to insert if the data blocks would no longer fit within the data
+    /// buffer.
+    fn insert_event_value<'a>(
+        &mut self,
+        key: impl Into<HashKey<'a>>,
+        value: u16,
+        platform: Platform,
+    ) -> Result<Option<u16>, InsertEventError> {
+        let key = key.into();
+        let event_value = self.event_value().ok_or(InsertEventError::MissingChunk)?;
+        let previous = event_value.get(key.clone()).copied();
+
+        if previous.is_none() {
+            let hash = key.hash_code(platform.encoding())?;
+
+            GameDataChunk::check_capacity(self.chunks(), event_value.growth(1))?;
+            self.event_value_mut()
+                .ok_or(InsertEventError::MissingChunk)?
+                .push(hash, value);
+        } else if let Some(current) = self.event_value_mut().and_then(|v| v.get_mut(key)) {
+            *current = value;
+        }
+
+        Ok(previous)
+    }
+
+    /// Inserts every event value from a map keyed by label or hexadecimal string.
+    ///
+    /// Labels are hashed under the character encoding of the platform, and the
+    /// key-value pairs are inserted in the order of their keys. Nothing is
+    /// inserted if a key fails to convert or the new keys would not fit within
+    /// the data buffer.
+    fn import_event_values(
+        &mut self,
+        values: &HashMap<String, u16>,
+        platform: Platform,
+    ) -> Result<(), InsertEventError> {
+        let mut values = values.iter().collect::<Vec<_>>();
+
+        values.sort_unstable_by_key(|(label, _)| *label);
+
+        let values = values
+            .into_iter()
+            .map(|(label, value)| {
+                let hash = match HashCode::from_hex_str(label) {
+                    Ok(hash) => hash,
+                    Err(ParseHexError::MissingPrefix) => platform.encoding().hash(label)?,
+                    Err(error) => return Err(error.into()),
+                };
+
+                Ok((hash, *value))
+            })
+            .collect::<Result<Vec<_>, InsertEventError>>()?;
+
+        let event_value = self.event_value().ok_or(InsertEventError::MissingChunk)?;
+        let mut new_keys = values
+            .iter()
+            .map(|(key, _)| *key)
+            .filter(|key| !event_value.contains(*key))
+            .collect::<Vec<_>>();
+
+        new_keys.sort_unstable_by_key(|key| key.into_raw());
+        new_keys.dedup();
+
+        GameDataChunk::check_capacity(self.chunks(), event_value.growth(new_keys.len()))?;
+
+        let event_value = self
+            .event_value_mut()
+            .ok_or(InsertEventError::MissingChunk)?;
+
+        for (key, value) in values {
+            match event_value.get_mut(key) {
+                Some(previous) => *previous = value,
+                None => event_value.push(key, value),
+            }
+        }
+
+        Ok(())
+    }
+
+    /// Returns a reference to the galaxy state, if present.
+    fn galaxy(&self) -> Option<&GameDataAllGalaxyStorage> {
+        self.chunks().iter().find_map(|c| match c {
+            GameDataChunk::Galaxy(chunk) => Some(&chunk.inner),
+            _ => None,
+        })
+    }
+
+    /// Returns a reference to the Hungry Luma state, if present.
+    fn star_piece_alms(&self) -> Option<&StarPieceAlmsStorage> {
+        self.chunks().iter().find_map(|c| match c {
+            GameDataChunk::StarPieceAlms(chunk) => Some(&chunk.inner),
+            _ => None,
+        })
+    }
+
+    /// Summarizes the collection progress, treating a missing galaxy state as empty.
+    fn summary(&self) -> GameDataSummary {
+        self.galaxy().map(GameDataSummary::new).unwrap_or_default()
+    }
+
+    /// Lists the collectibles remaining, treating missing data blocks as empty.
+    fn completion(&self) -> CompletionReport {
+        CompletionReport::new(self.galaxy())
+    }
+
+    /// Moves the story to the milestone, updating the player, event flag and
+    /// galaxy states together.
+    fn progress_to(
+        &mut self,
+        milestone: Milestone,
+        platform: Platform,
+    ) -> Result<(), ProgressError> {
+        milestone.apply(self.chunks_mut(), platform)
+    }
+}
+
+impl GameDataHolder for BinaryDataChunkHolder<GameDataChunk> {
+    fn chunks(&self) -> &[GameDataChunk] {
+        &self.chunks
+    }
+
+    fn chunks_mut(&mut self) -> &mut [GameDataChunk] {
+        &mut self.chunks
+    }
+}
@@ -1,11 +1,22 @@
 //! Types associated with key-value pair state, where each value is a Boolean.
 
+use std::slice;
+
 use binrw::binrw;
-use galaxy_save_core::{bin::Chunk, event::GameEventFlag, hash::HashCode};
+use galaxy_save_core::{
+    bin::{BinaryDataChunk, Chunk},
+    event::GameEventFlag,
+    hash::{HashCode, HashKey},
+};
 
 #[cfg(feature = "serde")]
 use serde::{Deserialize, Serialize};
 
+use crate::events::Flag;
+
+#[cfg(test)]
+mod tests;
+
 /// A container for key-value pair state, where each value is a Boolean.
 #[binrw]
 #[br(import(data_size: usize))]
@@ -18,24 +29,80 @@ pub struct GameEventFlagStorage {
 }
 
 impl GameEventFlagStorage {
-    /// Returns the value corresponding to the key.
-    pub fn get(&self, key: impl Into<HashCode>) -> Option<bool> {
-        let key = key.into();
+    /// Returns an iterator over the key-value pairs.
+    pub fn iter(&self) -> slice::Iter<'_, GameEventFlag> {
+        self.event_flag.iter()
+    }
+
+    /// Returns an iterator over the key-value pairs that allows modifying each value.
+    pub fn iter_mut(&mut self) -> slice::IterMut<'_, GameEventFlag> {
+        self.event_flag.iter_mut()
+    }
+
+    /// Returns `true` if the storage contains a value for the key.
+    pub fn contains<'a>(&self, key: impl Into<HashKey<'a>>) -> bool {
+        self.position(key).is_some()
+    }
 
-        self.event_flag
-            .iter()
-            .find(|f| **f == key)
-            .map(|f| f.value())
+    /// Returns the value corresponding to the key.
+    pub fn get<'a>(&self, key: impl Into<HashKey<'a>>) -> Option<bool> {
+        self.position(key).map(|i| self.event_flag[i].value())
     }
 
-    /// Sets the value corresponding to the key.
-    pub fn set(&mut self, key: impl Into<HashCode>, value: bool) {
-        let key = key.into();
+    /// Returns the value corresponding to a known key.
+    pub fn get_flag(&self, flag: Flag) -> Option<bool> {
+        self.get(flag)
+    }
 
-        if let Some(flag) = self.event_flag.iter_mut().find(|f| **f == key) {
-            flag.set(value);
+    /// Sets the value corresponding to the key, if present.
+    pub fn set<'a>(&mut self, key: impl Into<HashKey<'a>>, value: bool) {
+        if let Some(index) = self.position(key) {
+            self.event_flag[index].set(value);
         }
     }
+
+    /// Sets the value corresponding to a known key, if present.
+    pub fn set_flag(&mut self, flag: Flag, value: bool) {
+        self.set(flag, value);
+    }
+
+    /// Appends a key-value pair for a new key.
+    ///
+    /// Whether the key is new and fits within the data buffer is checked by
+    /// the caller, as by [`GameDataHolder::insert_event_flag`].
+    ///
+    /// [`GameDataHolder::insert_event_flag`]: crate::chunks::game::GameDataHolder::insert_event_flag
+    pub(crate) fn push(&mut self, key: HashCode, value: bool) {
+        self.event_flag.push(GameEventFlag::new(key, value));
+    }
+
+    /// Removes a key from the storage, returning the value if present.
+    pub fn remove<'a>(&mut self, key: impl Into<HashKey<'a>>) -> Option<bool> {
+        let index = self.position(key)?;
+
+        Some(self.event_flag.remove(index).value())
+    }
+
+    /// Returns the number of bytes the data block grows by when `additional`
+    /// new keys are inserted, in little-endian byte order.
+    pub(crate) fn growth(&self, additional: usize) -> usize {
+        let data_size = self.event_flag.len() * size_of::<GameEventFlag>();
+        let additional = additional * size_of::<GameEventFlag>();
+
+        BinaryDataChunk::<Self>::size_le(data_size + additional)
+            - BinaryDataChunk::<Self>::size_le(data_size)
+    }
+
+    /// Returns the index of the key-value pair corresponding to the key.
+    ///
+    /// A label matches its hash under any character encoding, trying each in
+    /// the order of [`HashKey::hash_codes`].
+    fn position<'a>(&self, key: impl Into<HashKey<'a>>) -> Option<usize> {
+        key.into()
+            .hash_codes()
+            .into_iter()
+            .find_map(|hash| self.event_flag.iter().position(|f| *f == hash))
+    }
 }
 
 impl Chunk for GameEventFlagStorage {
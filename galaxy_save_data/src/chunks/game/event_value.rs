@@ -1,12 +1,23 @@
 //! Types associated with key-value pair state, where each value is a 16-bit
 //! unsigned integer.
 
+use std::slice;
+
 use binrw::binrw;
-use galaxy_save_core::{bin::Chunk, event::GameEventValue, hash::HashCode};
+use galaxy_save_core::{
+    bin::{BinaryDataChunk, Chunk},
+    event::GameEventValue,
+    hash::{HashCode, HashKey},
+};
 
 #[cfg(feature = "serde")]
 use serde::{Deserialize, Serialize};
 
+use crate::events::Value;
+
+#[cfg(test)]
+mod tests;
+
 /// A container for key-value pair state, where each value is a 16-bit unsigned integer.
 #[binrw]
 #[br(import(data_size: usize))]
@@ -19,24 +30,87 @@ pub struct GameEventValueStorage {
 }
 
 impl GameEventValueStorage {
-    /// Returns a reference to the value corresponding to the key.
-    pub fn get(&self, key: impl Into<HashCode>) -> Option<&u16> {
-        let key = key.into();
+    /// Returns an iterator over the key-value pairs.
+    pub fn iter(&self) -> slice::Iter<'_, GameEventValue> {
+        self.event_value.iter()
+    }
+
+    /// Returns an iterator over the key-value pairs that allows modifying each value.
+    pub fn iter_mut(&mut self) -> slice::IterMut<'_, GameEventValue> {
+        self.event_value.iter_mut()
+    }
 
-        self.event_value
-            .iter()
-            .find(|v| **v == key)
-            .map(|v| v.value())
+    /// Returns the number of key-value pairs.
+    pub fn len(&self) -> usize {
+        self.event_value.len()
+    }
+
+    /// Returns `true` if the storage contains no key-value pairs.
+    pub fn is_empty(&self) -> bool {
+        self.event_value.is_empty()
+    }
+
+    /// Returns `true` if the storage contains a value for the key.
+    pub fn contains<'a>(&self, key: impl Into<HashKey<'a>>) -> bool {
+        self.position(key).is_some()
+    }
+
+    /// Returns a reference to the value corresponding to the key.
+    pub fn get<'a>(&self, key: impl Into<HashKey<'a>>) -> Option<&u16> {
+        self.position(key).map(|i| self.event_value[i].value())
     }
 
     /// Returns a mutable reference to the value corresponding to the key.
-    pub fn get_mut(&mut self, key: impl Into<HashCode>) -> Option<&mut u16> {
-        let key = key.into();
+    pub fn get_mut<'a>(&mut self, key: impl Into<HashKey<'a>>) -> Option<&mut u16> {
+        self.position(key).map(|i| self.event_value[i].value_mut())
+    }
+
+    /// Returns a reference to the value corresponding to a known key.
+    pub fn get_value(&self, value: Value) -> Option<&u16> {
+        self.get(value)
+    }
+
+    /// Returns a mutable reference to the value corresponding to a known key.
+    pub fn get_value_mut(&mut self, value: Value) -> Option<&mut u16> {
+        self.get_mut(value)
+    }
+
+    /// Appends a key-value pair for a new key.
+    ///
+    /// Whether the key is new and fits within the data buffer is checked by
+    /// the caller, as by [`GameDataHolder::insert_event_value`].
+    ///
+    /// [`GameDataHolder::insert_event_value`]: crate::chunks::game::GameDataHolder::insert_event_value
+    pub(crate) fn push(&mut self, key: HashCode, value: u16) {
+        self.event_value.push(GameEventValue::new(key, value));
+    }
+
+    /// Removes a key from the storage, returning the value if present.
+    pub fn remove<'a>(&mut self, key: impl Into<HashKey<'a>>) -> Option<u16> {
+        let index = self.position(key)?;
+
+        Some(*self.event_value.remove(index).value())
+    }
+
+    /// Returns the number of bytes the data block grows by when `additional`
+    /// new keys are inserted, in little-endian byte order.
+    pub(crate) fn growth(&self, additional: usize) -> usize {
+        let data_size = self.event_value.len() * size_of::<GameEventValue>();
+        let additional = additional * size_of::<GameEventValue>();
+
+        BinaryDataChunk::<Self>::size_le(data_size + additional)
+            - BinaryDataChunk::<Self>::size_le(data_size)
+    }
 
-        self.event_value
-            .iter_mut()
-            .find(|v| **v == key)
-            .map(|v| v.value_mut())
+    /// Returns the index of the key-value pair corresponding to the key.
+    ///
+    /// A label matches its hash under any character encoding, trying each in
+    /// the order of [`HashKey::hash_codes`].
+    fn position<'a>(&self, key: impl Into<HashKey<'a>>) -> Option<usize> {
+        key.into()
+            .hash_codes()
+            .into_iter()
+            .find_map(|hash| self.event_value.iter().position(|v| *v == hash))
     }
 }
 
@@ -0,0 +1,185 @@
+use std::collections::HashMap;
+
+use binrw::{BinRead, BinWrite, io::Cursor};
+use galaxy_save_core::{
+    bin::{BinaryDataChunkHolder, CapacityError},
+    event::InsertEventError,
+    hash::Encoding,
+    save::Platform,
+};
+
+use super::*;
+use crate::chunks::game::{GameDataChunk, GameDataHolder};
+
+/// Returns a container holding an event value state without key-value pairs.
+fn holder() -> BinaryDataChunkHolder<GameDataChunk> {
+    let event_value = GameEventValueStorage {
+        event_value: Vec::new(),
+    };
+
+    BinaryDataChunkHolder {
+        chunks: vec![GameDataChunk::EventValue(BinaryDataChunk {
+            inner: event_value,
+        })],
+    }
+}
+
+/// Fills the data buffer with placeholder keys.
+fn fill(holder: &mut BinaryDataChunkHolder<GameDataChunk>) {
+    let mut raw = 0;
+
+    while holder.remaining_capacity().unwrap() > 0 {
+        holder
+            .event_value_mut()
+            .unwrap()
+            .push(HashCode::from_raw(raw), 0);
+        raw += 1;
+    }
+}
+
+#[test]
+fn test_insert() {
+    let mut holder = holder();
+
+    assert_eq!(
+        holder
+            .insert_event_value("ValueA", 3, Platform::Wii)
+            .unwrap(),
+        None
+    );
+    assert_eq!(
+        holder
+            .insert_event_value("ValueA", 5, Platform::Wii)
+            .unwrap(),
+        Some(3)
+    );
+    assert_eq!(holder.event_value().unwrap().get("ValueA"), Some(&5));
+    assert_eq!(holder.event_value().unwrap().len(), 1);
+}
+
+#[test]
+fn test_insert_hashes_under_platform_encoding() {
+    let label = "ハチマリオ初変身";
+    let mut holder = holder();
+
+    holder.insert_event_value(label, 3, Platform::Wii).unwrap();
+
+    let event_value = holder.event_value().unwrap();
+
+    assert_eq!(
+        event_value.get(Encoding::ShiftJis.hash(label).unwrap()),
+        Some(&3)
+    );
+    assert_eq!(event_value.get(Encoding::Utf8.hash(label).unwrap()), None);
+}
+
+#[test]
+fn test_insert_exceeds_capacity() {
+    let mut holder = holder();
+    fill(&mut holder);
+
+    assert!(matches!(
+        holder.insert_event_value("ValueA", 3, Platform::Wii),
+        Err(InsertEventError::Capacity(CapacityError::BufferOverflow(_)))
+    ));
+    assert!(!holder.event_value().unwrap().contains("ValueA"));
+}
+
+#[test]
+fn test_insert_existing_key_at_capacity() {
+    let mut holder = holder();
+    fill(&mut holder);
+
+    assert_eq!(
+        holder
+            .insert_event_value(HashCode::from_raw(0), 3, Platform::Wii)
+            .unwrap(),
+        Some(0)
+    );
+}
+
+#[test]
+fn test_import() {
+    let mut holder = holder();
+    holder
+        .insert_event_value(HashCode::from_raw(0x1234), 1, Platform::Wii)
+        .unwrap();
+
+    let values = HashMap::from([
+        ("0x1234".to_string(), 2),
+        ("0x5678".to_string(), 3),
+        ("ValueA".to_string(), 4),
+    ]);
+
+    holder
+        .import_event_values(&values, Platform::Switch)
+        .unwrap();
+
+    let event_value = holder.event_value().unwrap();
+
+    assert_eq!(event_value.get(HashCode::from_raw(0x1234)), Some(&2));
+    assert_eq!(event_value.get(HashCode::from_raw(0x5678)), Some(&3));
+    assert_eq!(event_value.get("ValueA"), Some(&4));
+    assert_eq!(event_value.len(), 3);
+}
+
+#[test]
+fn test_import_exceeds_capacity() {
+    let mut holder = holder();
+    fill(&mut holder);
+
+    let values = HashMap::from([("0x0".to_string(), 2), ("0xFFFFFFFF".to_string(), 3)]);
+
+    assert!(matches!(
+        holder.import_event_values(&values, Platform::Wii),
+        Err(InsertEventError::Capacity(CapacityError::BufferOverflow(_)))
+    ));
+
+    let event_value = holder.event_value().unwrap();
+
+    assert_eq!(event_value.get(HashCode::from_raw(0)), Some(&0));
+    assert!(!event_value.contains(HashCode::from_raw(0xFFFFFFFF)));
+}
+
+#[test]
+fn test_import_invalid_key() {
+    let mut holder = holder();
+    let values = HashMap::from([("0x1234".to_string(), 2), ("0xZZ".to_string(), 3)]);
+
+    assert!(matches!(
+        holder.import_event_values(&values, Platform::Wii),
+        Err(InsertEventError::ParseHex(_))
+    ));
+    assert!(holder.event_value().unwrap().is_empty());
+}
+
+#[test]
+fn test_remove() {
+    let mut holder = holder();
+    holder
+        .insert_event_value("ValueA", 3, Platform::Wii)
+        .unwrap();
+
+    let event_value = holder.event_value_mut().unwrap();
+
+    assert_eq!(event_value.remove("ValueA"), Some(3));
+    assert_eq!(event_value.remove("ValueA"), None);
+}
+
+#[test]
+fn test_read_get_value_under_any_encoding() {
+    let value = Value::PenguinRaceOceanRingLo;
+
+    for platform in [Platform::Wii, Platform::Switch] {
+        let hash = HashCode::for_label(value.label(), platform).unwrap();
+        let mut writer = Cursor::new(Vec::new());
+        GameEventValue::new(hash, 3).write_be(&mut writer).unwrap();
+
+        let mut reader = Cursor::new(writer.into_inner());
+        let event_value =
+            GameEventValueStorage::read_be_args(&mut reader, (size_of::<GameEventValue>(),))
+                .unwrap();
+
+        assert_eq!(event_value.get_value(value), Some(&3), "{platform:?}");
+    }
+}
@@ -1,15 +1,25 @@
 //! Types associated with galaxy state.
 
-use binrw::binrw;
+use std::{
+    io::{Read, Seek, SeekFrom, Write},
+    slice,
+};
+
+use binrw::{BinRead, BinResult, BinWrite, Endian, binrw};
 use galaxy_save_core::{
     array::BitArray8,
-    bin::{BinaryDataContentHeaderSerializer, Chunk, HeaderSerializer},
+    bin::{BinaryDataChunk, BinaryDataContentHeaderSerializer, Chunk, HeaderSerializer},
     hash::{HashCode, HashCode16},
 };
 
 #[cfg(feature = "serde")]
 use serde::{Deserialize, Serialize};
 
+use crate::catalog::Galaxy;
+
+#[cfg(test)]
+mod tests;
+
 /// A container for galaxy state.
 #[binrw]
 #[br(import(_data_size: usize))]
@@ -21,16 +31,35 @@ pub struct GameDataAllGalaxyStorage {
     #[bw(calc = galaxy.len() as u16)]
     galaxy_num: u16,
 
+    /// The dynamic reader/writer for the galaxy states, as written by
+    /// [`dyn_serializer`](Self::dyn_serializer).
     #[br(temp)]
-    #[bw(calc = GameDataSomeGalaxyStorage::header_serializer())]
+    #[bw(calc = self.dyn_serializer())]
     _serializer: BinaryDataContentHeaderSerializer<GameDataSomeGalaxyStorage>,
 
     /// The collection of galaxy states.
-    #[br(count = galaxy_num as usize)]
+    #[br(count = galaxy_num as usize, args { inner: (&_serializer,) })]
+    #[bw(args(&_serializer))]
     galaxy: Vec<GameDataSomeGalaxyStorage>,
+
+    /// The dynamic reader/writer for the galaxy states read from the file, if any.
+    #[br(calc = Some(_serializer))]
+    #[bw(ignore)]
+    #[cfg_attr(feature = "serde", serde(skip))]
+    serializer: Option<BinaryDataContentHeaderSerializer<GameDataSomeGalaxyStorage>>,
 }
 
 impl GameDataAllGalaxyStorage {
+    /// Returns an iterator over the galaxy states.
+    pub fn iter(&self) -> slice::Iter<'_, GameDataSomeGalaxyStorage> {
+        self.galaxy.iter()
+    }
+
+    /// Returns an iterator that allows modifying each galaxy state.
+    pub fn iter_mut(&mut self) -> slice::IterMut<'_, GameDataSomeGalaxyStorage> {
+        self.galaxy.iter_mut()
+    }
+
     /// Returns a reference to the [`GameDataSomeGalaxyStorage`] corresponding to the given key.
     pub fn get(&self, galaxy_name: impl Into<HashCode>) -> Option<&GameDataSomeGalaxyStorage> {
         let galaxy_name = HashCode16::from(galaxy_name.into());
@@ -49,6 +78,84 @@ impl GameDataAllGalaxyStorage {
             .iter_mut()
             .find(|v| v.galaxy_name == galaxy_name)
     }
+
+    /// Returns a mutable reference to the [`GameDataSomeGalaxyStorage`] corresponding to the given key,
+    /// inserting a galaxy state without collected Stars if missing.
+    ///
+    /// The optional fields of the inserted galaxy state are present if described by
+    /// [`dyn_serializer`](Self::dyn_serializer).
+    pub fn insert(&mut self, galaxy_name: impl Into<HashCode>) -> &mut GameDataSomeGalaxyStorage {
+        let galaxy_name = HashCode16::from(galaxy_name.into());
+
+        let index = match self
+            .galaxy
+            .iter()
+            .position(|v| v.galaxy_name == galaxy_name)
+        {
+            Some(index) => index,
+            None => {
+                let serializer = self.dyn_serializer();
+                let storage = GameDataSomeGalaxyStorage {
+                    clear_stage_num: serializer.contains("mClearStageNum").then(Default::default),
+                    miss_stage_num: serializer.contains("mMissStageNum").then(Default::default),
+                    ..GameDataSomeGalaxyStorage::new(galaxy_name)
+                };
+
+                self.galaxy.push(storage);
+                self.galaxy.len() - 1
+            }
+        };
+
+        &mut self.galaxy[index]
+    }
+
+    /// Removes the [`GameDataSomeGalaxyStorage`] corresponding to the given key, returning it if present.
+    pub fn remove(
+        &mut self,
+        galaxy_name: impl Into<HashCode>,
+    ) -> Option<GameDataSomeGalaxyStorage> {
+        let galaxy_name = HashCode16::from(galaxy_name.into());
+        let index = self
+            .galaxy
+            .iter()
+            .position(|v| v.galaxy_name == galaxy_name)?;
+
+        Some(self.galaxy.remove(index))
+    }
+
+    /// Returns the number of bytes the data block grows by when the number of
+    /// galaxy states is increased by `additional`.
+    pub(crate) fn growth(&self, additional: usize) -> usize {
+        let serializer = self.dyn_serializer();
+        let data_size = size_of::<u16>()
+            + serializer.header_size()
+            + self.galaxy.len() * serializer.data_size();
+        let additional = additional * serializer.data_size();
+
+        BinaryDataChunk::<Self>::size_le(data_size + additional)
+            - BinaryDataChunk::<Self>::size_le(data_size)
+    }
+
+    /// Creates the dynamic reader/writer for the galaxy states.
+    ///
+    /// The dynamic reader/writer read from the file is kept, extended with
+    /// the optional fields present in any galaxy state which it lacks. A
+    /// container not read from a file describes only the optional fields
+    /// present in any galaxy state.
+    pub fn dyn_serializer(&self) -> BinaryDataContentHeaderSerializer<GameDataSomeGalaxyStorage> {
+        let present = |f: fn(&GameDataSomeGalaxyStorage) -> bool| self.galaxy.iter().any(f);
+        let serializer = GameDataSomeGalaxyStorage {
+            clear_stage_num: present(|g| g.clear_stage_num.is_some()).then(Default::default),
+            miss_stage_num: present(|g| g.miss_stage_num.is_some()).then(Default::default),
+            ..GameDataSomeGalaxyStorage::new(HashCode16::default())
+        }
+        .dyn_header_serializer();
+
+        match &self.serializer {
+            Some(read) => read.widen(&serializer),
+            None => serializer,
+        }
+    }
 }
 
 impl Chunk for GameDataAllGalaxyStorage {
@@ -58,11 +165,16 @@ impl Chunk for GameDataAllGalaxyStorage {
 }
 
 /// A container for the state of a galaxy.
-#[binrw]
+///
+/// Each field is read from and written to its offset described by the dynamic
+/// reader/writer shared by every galaxy state. Fields it does not describe are
+/// absent when read and not written, and bytes it describes for unknown fields
+/// are written as zero.
 #[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
 #[derive(Debug, HeaderSerializer)]
 pub struct GameDataSomeGalaxyStorage {
     /// The hashed internal name of the galaxy, truncated to the least significant 16 bits.
+    #[cfg_attr(feature = "serde", serde(with = "crate::catalog::serde_galaxy_name"))]
     #[header_serializer(name = "mGalaxyName")]
     galaxy_name: HashCode16,
 
@@ -77,13 +189,136 @@ pub struct GameDataSomeGalaxyStorage {
     /// The greatest number of collected coins for each mission.
     #[header_serializer(name = "mMaxCoinNum")]
     pub max_coin_num: [u16; u8::BITS as usize],
-    /*
+
     /// The number of times each base mission was cleared.
+    #[cfg_attr(
+        feature = "serde",
+        serde(default, skip_serializing_if = "Option::is_none")
+    )]
     #[header_serializer(name = "mClearStageNum")]
-    pub clear_stage_num: [u16; u8::BITS as usize],
+    pub clear_stage_num: Option<[u16; u8::BITS as usize]>,
 
     /// The number of lives lost for each base mission.
+    #[cfg_attr(
+        feature = "serde",
+        serde(default, skip_serializing_if = "Option::is_none")
+    )]
     #[header_serializer(name = "mMissStageNum")]
-    pub miss_stage_num: [u16; u8::BITS as usize],
-    */
+    pub miss_stage_num: Option<[u16; u8::BITS as usize]>,
+}
+
+impl BinRead for GameDataSomeGalaxyStorage {
+    type Args<'a> = (&'a BinaryDataContentHeaderSerializer<Self>,);
+
+    fn read_options<R: Read + Seek>(
+        reader: &mut R,
+        endian: Endian,
+        (serializer,): Self::Args<'_>,
+    ) -> BinResult<Self> {
+        let start = reader.stream_position()?;
+        let storage = Self {
+            galaxy_name: serializer
+                .read_field("mGalaxyName", reader, endian, start)?
+                .unwrap_or_default(),
+            power_star_flag: serializer
+                .read_field("mPowerStarFlag", reader, endian, start)?
+                .unwrap_or_default(),
+            first_play_flag: serializer
+                .read_field("mFirstPlayFlag", reader, endian, start)?
+                .unwrap_or_default(),
+            max_coin_num: serializer
+                .read_field("mMaxCoinNum", reader, endian, start)?
+                .unwrap_or_default(),
+            clear_stage_num: serializer.read_field("mClearStageNum", reader, endian, start)?,
+            miss_stage_num: serializer.read_field("mMissStageNum", reader, endian, start)?,
+        };
+
+        reader.seek(SeekFrom::Start(start + serializer.data_size() as u64))?;
+
+        Ok(storage)
+    }
+}
+
+impl BinWrite for GameDataSomeGalaxyStorage {
+    type Args<'a> = (&'a BinaryDataContentHeaderSerializer<Self>,);
+
+    fn write_options<W: Write + Seek>(
+        &self,
+        writer: &mut W,
+        endian: Endian,
+        (serializer,): Self::Args<'_>,
+    ) -> BinResult<()> {
+        let start = writer.stream_position()?;
+
+        vec![0u8; serializer.data_size()].write_options(writer, endian, ())?;
+        serializer.write_field(
+            "mGalaxyName",
+            Some(&self.galaxy_name),
+            writer,
+            endian,
+            start,
+        )?;
+        serializer.write_field(
+            "mPowerStarFlag",
+            Some(&self.power_star_flag),
+            writer,
+            endian,
+            start,
+        )?;
+        serializer.write_field(
+            "mFirstPlayFlag",
+            Some(&self.first_play_flag),
+            writer,
+            endian,
+            start,
+        )?;
+        serializer.write_field(
+            "mMaxCoinNum",
+            Some(&self.max_coin_num),
+            writer,
+            endian,
+            start,
+        )?;
+        serializer.write_field(
+            "mClearStageNum",
+            self.clear_stage_num.as_ref(),
+            writer,
+            endian,
+            start,
+        )?;
+        serializer.write_field(
+            "mMissStageNum",
+            self.miss_stage_num.as_ref(),
+            writer,
+            endian,
+            start,
+        )?;
+        writer.seek(SeekFrom::Start(start + serializer.data_size() as u64))?;
+
+        Ok(())
+    }
+}
+
+impl GameDataSomeGalaxyStorage {
+    /// Creates a new `GameDataSomeGalaxyStorage` without collected Stars or optional fields.
+    pub(crate) fn new(galaxy_name: HashCode16) -> Self {
+        Self {
+            galaxy_name,
+            power_star_flag: BitArray8::new(),
+            first_play_flag: BitArray8::new(),
+            max_coin_num: Default::default(),
+            clear_stage_num: None,
+            miss_stage_num: None,
+        }
+    }
+
+    /// Returns the hashed internal name of the galaxy.
+    pub const fn galaxy_name(&self) -> HashCode16 {
+        self.galaxy_name
+    }
+
+    /// Returns the catalog entry of the galaxy, if known.
+    pub fn galaxy(&self) -> Option<Galaxy> {
+        Galaxy::from_hash_code(self.galaxy_name)
+    }
 }
@@ -0,0 +1,160 @@
+use binrw::{BinRead, BinWrite, io::Cursor};
+use galaxy_save_core::bin::BinaryDataContentAttribute;
+
+use super::*;
+
+/// Writes a container for galaxy state and reads it back.
+fn round_trip(storage: &GameDataAllGalaxyStorage) -> (GameDataAllGalaxyStorage, Vec<u8>) {
+    let mut writer = Cursor::new(Vec::new());
+    storage.write_be(&mut writer).unwrap();
+
+    let bytes = writer.into_inner();
+    let storage =
+        GameDataAllGalaxyStorage::read_be_args(&mut Cursor::new(&bytes), (bytes.len(),)).unwrap();
+
+    (storage, bytes)
+}
+
+/// Returns a container without galaxy states.
+fn empty() -> GameDataAllGalaxyStorage {
+    GameDataAllGalaxyStorage {
+        galaxy: Vec::new(),
+        serializer: None,
+    }
+}
+
+/// Returns the descriptor for the field of the serialized name at the offset.
+fn attribute(name: &str, offset: u16) -> BinaryDataContentAttribute {
+    BinaryDataContentAttribute {
+        key: HashCode16::from(name),
+        offset,
+    }
+}
+
+/// Returns the dynamic reader/writer for galaxy states storing the lives lost
+/// before the number of clears, unlike the declaration order.
+fn reordered_serializer() -> BinaryDataContentHeaderSerializer<GameDataSomeGalaxyStorage> {
+    BinaryDataContentHeaderSerializer::new(
+        vec![
+            attribute("mGalaxyName", 0),
+            attribute("mPowerStarFlag", 2),
+            attribute("mFirstPlayFlag", 3),
+            attribute("mMaxCoinNum", 4),
+            attribute("mMissStageNum", 20),
+            attribute("mClearStageNum", 36),
+        ],
+        52,
+    )
+}
+
+#[test]
+fn test_round_trip_base() {
+    let mut storage = empty();
+    storage.insert(Galaxy::EggStarGalaxy).power_star_flag.set(1);
+
+    let (read, bytes) = round_trip(&storage);
+    let galaxy = read.get(Galaxy::EggStarGalaxy).unwrap();
+
+    assert!(galaxy.power_star_flag.test(1));
+    assert!(galaxy.clear_stage_num.is_none());
+    assert!(galaxy.miss_stage_num.is_none());
+    assert!(bytes.ends_with(&[0; 16]));
+}
+
+#[test]
+fn test_round_trip_counters() {
+    let mut storage = empty();
+    storage.insert(Galaxy::EggStarGalaxy);
+
+    let galaxy = storage.insert(Galaxy::HoneyBeeKingdomGalaxy);
+    galaxy.clear_stage_num = Some([1, 2, 0, 0, 0, 0, 0, 0]);
+    galaxy.miss_stage_num = Some([0, 3, 0, 0, 0, 0, 0, 0]);
+
+    let (read, _) = round_trip(&storage);
+    let first = read.get(Galaxy::EggStarGalaxy).unwrap();
+    let second = read.get(Galaxy::HoneyBeeKingdomGalaxy).unwrap();
+
+    assert_eq!(first.clear_stage_num, Some([0; 8]));
+    assert_eq!(first.miss_stage_num, Some([0; 8]));
+    assert_eq!(second.clear_stage_num, Some([1, 2, 0, 0, 0, 0, 0, 0]));
+    assert_eq!(second.miss_stage_num, Some([0, 3, 0, 0, 0, 0, 0, 0]));
+}
+
+#[test]
+fn test_insert_optional_fields() {
+    let mut storage = empty();
+    storage.insert(Galaxy::EggStarGalaxy);
+    storage.insert(Galaxy::HoneyBeeKingdomGalaxy).miss_stage_num = Some([0; 8]);
+
+    let galaxy = storage.insert(Galaxy::TriLegLv1Galaxy);
+
+    assert!(galaxy.clear_stage_num.is_none());
+    assert_eq!(galaxy.miss_stage_num, Some([0; 8]));
+}
+
+#[test]
+fn test_read_galaxy_by_offset() {
+    let serializer = reordered_serializer();
+    let mut bytes = [0u8; 52];
+    bytes[..2].copy_from_slice(&Galaxy::EggStarGalaxy.hash_code().trunc().to_be_bytes());
+    bytes[2] = 0b10;
+    bytes[21] = 3;
+    bytes[37] = 5;
+
+    let storage =
+        GameDataSomeGalaxyStorage::read_be_args(&mut Cursor::new(&bytes), (&serializer,)).unwrap();
+
+    assert_eq!(storage.galaxy(), Some(Galaxy::EggStarGalaxy));
+    assert!(storage.power_star_flag.test(1));
+    assert_eq!(storage.miss_stage_num, Some([3, 0, 0, 0, 0, 0, 0, 0]));
+    assert_eq!(storage.clear_stage_num, Some([5, 0, 0, 0, 0, 0, 0, 0]));
+
+    let mut writer = Cursor::new(Vec::new());
+    storage.write_be_args(&mut writer, (&serializer,)).unwrap();
+
+    assert_eq!(writer.into_inner(), bytes);
+}
+
+#[test]
+fn test_write_keeps_read_serializer() {
+    let mut storage = empty();
+    storage.insert(Galaxy::EggStarGalaxy).clear_stage_num = Some([1; 8]);
+
+    let (mut read, _) = round_trip(&storage);
+    read.remove(Galaxy::EggStarGalaxy);
+
+    let (mut read, _) = round_trip(&read);
+
+    assert!(read.dyn_serializer().contains("mClearStageNum"));
+    assert_eq!(
+        read.insert(Galaxy::EggStarGalaxy).clear_stage_num,
+        Some([0; 8])
+    );
+}
+
+#[test]
+fn test_write_widens_read_serializer() {
+    let mut serializer = reordered_serializer();
+    serializer.attributes.pop();
+
+    let mut storage = GameDataAllGalaxyStorage {
+        galaxy: Vec::new(),
+        serializer: Some(BinaryDataContentHeaderSerializer::new(
+            serializer.attributes,
+            36,
+        )),
+    };
+    storage.insert(Galaxy::EggStarGalaxy).clear_stage_num = Some([2; 8]);
+
+    let serializer = storage.dyn_serializer();
+
+    assert_eq!(serializer.offset_of("mMissStageNum"), Some(20));
+    assert_eq!(serializer.offset_of("mClearStageNum"), Some(36));
+    assert_eq!(serializer.data_size(), 52);
+
+    let (read, _) = round_trip(&storage);
+    let galaxy = read.get(Galaxy::EggStarGalaxy).unwrap();
+
+    assert_eq!(galaxy.miss_stage_num, Some([0; 8]));
+    assert_eq!(galaxy.clear_stage_num, Some([2; 8]));
+}
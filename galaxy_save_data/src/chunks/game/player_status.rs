@@ -1,15 +1,25 @@
 //! Types associated with player state.
 
-// use bilge::prelude::*;
+use bilge::prelude::*;
 use binrw::binrw;
 use galaxy_save_core::{bin::Chunk, hash::HashCode};
 
 #[cfg(feature = "serde")]
 use serde::{Deserialize, Serialize};
 
+#[cfg(test)]
+mod tests;
+
 /// A container for player state.
+///
+/// Reading fails if the data block is too large for the base data alone but
+/// too small for the statistics.
 #[binrw]
-#[br(import(_data_size: usize))]
+#[br(import(data_size: usize))]
+#[br(pre_assert(
+    data_size <= Self::BASE_SIZE || data_size >= Self::BASE_SIZE + GameDataPlayerStatusStats::SIZE,
+    "player status data block is too small for its statistics"
+))]
 #[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
 #[derive(Debug)]
 pub struct GameDataPlayerStatus {
@@ -47,11 +57,55 @@ pub struct GameDataPlayerStatus {
 
     /// The number of remaining lives.
     pub player_left: u16,
-    /*
+
+    /// The statistics following the remaining lives, present if the data block is large enough.
+    #[br(if(data_size >= Self::BASE_SIZE + GameDataPlayerStatusStats::SIZE))]
+    #[cfg_attr(
+        feature = "serde",
+        serde(default, skip_serializing_if = "Option::is_none")
+    )]
+    pub stats: Option<GameDataPlayerStatusStats>,
+}
+
+impl GameDataPlayerStatus {
+    /// The size of the data preceding the statistics, in bytes.
+    pub const BASE_SIZE: usize = size_of::<u8>() + size_of::<u32>() + size_of::<u16>();
+}
+
+impl Default for GameDataPlayerStatus {
+    fn default() -> Self {
+        Self {
+            story_progress: 0,
+            stocked_star_piece_num: 0,
+            player_left: 4,
+            stats: None,
+        }
+    }
+}
+
+impl Chunk for GameDataPlayerStatus {
+    fn hash_code() -> HashCode {
+        HashCode::from_raw(0x27C90F)
+    }
+}
+
+/// A collection of player statistics.
+#[binrw]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+#[derive(Debug, Default)]
+pub struct GameDataPlayerStatusStats {
     // TODO: Unused?
     unk1: u8,
 
     /// Determines if Rosalina's Storybook was opened.
+    ///
+    /// Reading fails if the stored value is neither `0` nor `1`.
+    #[br(try_map = |b: u8| match b {
+        0 => Ok(false),
+        1 => Ok(true),
+        _ => Err("picture book state is not a boolean"),
+    })]
+    #[bw(map = |b| u8::from(*b))]
     pub is_picture_book_opened: bool,
 
     /// The number of cutscenes skipped.
@@ -80,43 +134,17 @@ pub struct GameDataPlayerStatus {
 
     /// The number of missions cleared.
     pub play_num: u16,
-    */
-}
-
-impl Default for GameDataPlayerStatus {
-    fn default() -> Self {
-        Self {
-            story_progress: 0,
-            stocked_star_piece_num: 0,
-            player_left: 4,
-            /*
-            unk1: 0,
-            is_picture_book_opened: false,
-            demo_skip_num: 0,
-            music_play_seconds: 0,
-            p2_num: 0,
-            luigi_num: 0,
-            unk7: 0,
-            bosses_finished_flag: Default::default(),
-            unk9: 0,
-            unk10: 0,
-            play_num: 0,
-            */
-        }
-    }
 }
 
-impl Chunk for GameDataPlayerStatus {
-    fn hash_code() -> HashCode {
-        HashCode::from_raw(0x27C90F)
-    }
+impl GameDataPlayerStatusStats {
+    /// The size of the serialized statistics, in bytes.
+    pub const SIZE: usize = 25;
 }
 
-/*
 /// A collection of packed binary settings for defeated boss state.
 #[bitsize(32)]
 #[binrw]
-#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+#[cfg_attr(feature = "serde", derive(SerializeBits, DeserializeBits))]
 #[derive(DebugBits, Clone, Copy, DefaultBits, FromBits)]
 #[repr(transparent)]
 pub struct GameDataPlayerStatusBossesFinishedFlag {
@@ -176,4 +204,3 @@ pub struct GameDataPlayerStatusBossesFinishedFlag {
 
     reserved: u14,
 }
-*/
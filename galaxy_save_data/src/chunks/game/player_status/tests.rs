@@ -0,0 +1,79 @@
+use binrw::{BinRead, BinWrite, io::Cursor};
+
+use super::*;
+
+/// Reads a player status block and writes it back.
+fn round_trip(bytes: &[u8]) -> (GameDataPlayerStatus, Vec<u8>) {
+    let status =
+        GameDataPlayerStatus::read_be_args(&mut Cursor::new(bytes), (bytes.len(),)).unwrap();
+
+    let mut writer = Cursor::new(Vec::new());
+    status.write_be(&mut writer).unwrap();
+
+    (status, writer.into_inner())
+}
+
+#[test]
+fn test_default() {
+    let status = GameDataPlayerStatus::default();
+
+    assert_eq!(status.player_left, 4);
+    assert!(status.stats.is_none());
+}
+
+#[test]
+fn test_round_trip_base() {
+    let bytes = [60, 0x00, 0x00, 0x01, 0x2C, 0x00, 0x05];
+    assert_eq!(bytes.len(), GameDataPlayerStatus::BASE_SIZE);
+
+    let (status, written) = round_trip(&bytes);
+
+    assert_eq!(status.story_progress, 60);
+    assert_eq!(status.stocked_star_piece_num, 300);
+    assert_eq!(status.player_left, 5);
+    assert!(status.stats.is_none());
+    assert_eq!(written, bytes);
+}
+
+#[test]
+fn test_round_trip_stats() {
+    let mut bytes = vec![60, 0x00, 0x00, 0x01, 0x2C, 0x00, 0x05];
+    bytes.extend_from_slice(&[
+        0x00, 0x01, 0x00, 0x03, 0x01, 0x00, 0x00, 0x02, 0x00, 0x04, 0x00, 0x00, 0x00, 0x00, 0x80,
+        0x00, 0x00, 0x01, 0x00, 0x00, 0x00, 0x00, 0x01, 0x00, 0x78,
+    ]);
+    assert_eq!(
+        bytes.len(),
+        GameDataPlayerStatus::BASE_SIZE + GameDataPlayerStatusStats::SIZE
+    );
+
+    let (status, written) = round_trip(&bytes);
+    let stats = status.stats.as_ref().unwrap();
+
+    assert!(stats.is_picture_book_opened);
+    assert_eq!(stats.demo_skip_num, 3);
+    assert_eq!(stats.music_play_seconds, 256);
+    assert_eq!(stats.p2_num, 2);
+    assert_eq!(stats.luigi_num, 4);
+    assert!(stats.bosses_finished_flag.boss_begoman());
+    assert!(!stats.bosses_finished_flag.koopa_vs3());
+    assert_eq!(stats.play_num, 120);
+    assert_eq!(written, bytes);
+}
+
+#[test]
+fn test_read_picture_book_not_boolean() {
+    let mut bytes = vec![60, 0x00, 0x00, 0x01, 0x2C, 0x00, 0x05];
+    bytes.extend_from_slice(&[0; GameDataPlayerStatusStats::SIZE]);
+    bytes[GameDataPlayerStatus::BASE_SIZE + 1] = 2;
+
+    assert!(GameDataPlayerStatus::read_be_args(&mut Cursor::new(&bytes), (bytes.len(),)).is_err());
+}
+
+#[test]
+fn test_read_partial_stats() {
+    let mut bytes = vec![60, 0x00, 0x00, 0x01, 0x2C, 0x00, 0x05];
+    bytes.extend_from_slice(&[0; GameDataPlayerStatusStats::SIZE - 1]);
+
+    assert!(GameDataPlayerStatus::read_be_args(&mut Cursor::new(&bytes), (bytes.len(),)).is_err());
+}
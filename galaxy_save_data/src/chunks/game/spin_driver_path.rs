@@ -2,7 +2,7 @@
 
 use std::io::{Read, Seek, SeekFrom, Write};
 
-use binrw::{BinRead, BinResult, BinWrite, Endian, binread, binrw};
+use binrw::{BinRead, BinResult, BinWrite, Endian, binrw};
 use galaxy_save_core::{
     bin::Chunk,
     hash::{HashCode, HashCode16},
@@ -11,6 +11,9 @@ use galaxy_save_core::{
 #[cfg(feature = "serde")]
 use serde::{Deserialize, Serialize};
 
+#[cfg(test)]
+mod tests;
+
 /// A container for Launch Star path state.
 #[binrw]
 #[br(import(_data_size: usize))]
@@ -55,27 +58,51 @@ impl Chunk for SpinDriverPathStorage {
 }
 
 /// A container for Launch Star path state in a galaxy.
-#[binread]
+///
+/// The serialized struct is prefixed by its size, in bytes, the number of
+/// stored base missions and a reserved byte.
 #[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
 #[derive(Debug)]
 pub struct SpinDriverPathStorageGalaxy {
     /// The hashed internal name of the galaxy, truncated to the least significant 16 bits.
+    #[cfg_attr(feature = "serde", serde(with = "crate::catalog::serde_galaxy_name"))]
     galaxy_name: HashCode16,
 
-    /// The size of the serialized struct, in bytes.
-    #[br(temp)]
-    _data_size: u16,
+    /// The collection of associated base missions.
+    pub scenario: Vec<SpinDriverPathStorageScenario>,
+}
 
-    /// The number of stored base missions.
-    #[br(temp)]
-    scenario_num: u8,
+impl BinRead for SpinDriverPathStorageGalaxy {
+    type Args<'a> = ();
 
-    #[br(temp)]
-    _reserved: u8,
+    fn read_options<R: Read + Seek>(
+        reader: &mut R,
+        endian: Endian,
+        _args: Self::Args<'_>,
+    ) -> BinResult<Self> {
+        let start_pos = reader.stream_position()?;
+        let galaxy_name = HashCode16::read_options(reader, endian, ())?;
+        let expected_data_size = u16::read_options(reader, endian, ())? as u64;
+        let scenario_num = u8::read(reader)?;
+        let _reserved = u8::read(reader)?;
 
-    /// The collection of associated base missions.
-    #[br(count = scenario_num as usize)]
-    pub scenario: Vec<SpinDriverPathStorageScenario>,
+        let mut scenario = Vec::with_capacity(scenario_num as usize);
+
+        for _ in 0..scenario_num {
+            scenario.push(SpinDriverPathStorageScenario::read_options(
+                reader,
+                endian,
+                (),
+            )?);
+        }
+
+        check_data_size(reader, start_pos, expected_data_size)?;
+
+        Ok(Self {
+            galaxy_name,
+            scenario,
+        })
+    }
 }
 
 impl BinWrite for SpinDriverPathStorageGalaxy {
@@ -117,8 +144,8 @@ impl BinWrite for SpinDriverPathStorageGalaxy {
 #[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
 #[derive(Debug)]
 pub struct SpinDriverPathStorageScenario {
-    /// The collection of Launch Star path states.
-    pub one: Vec<SpinDriverPathStorageOne>,
+    /// The collection of zones, in the order stored.
+    pub zone: Vec<SpinDriverPathStorageZone>,
 }
 
 impl SpinDriverPathStorageScenario {
@@ -142,13 +169,22 @@ impl BinRead for SpinDriverPathStorageScenario {
     ) -> BinResult<Self> {
         let start_pos = reader.stream_position()?;
         let expected_data_size = u16::read_options(reader, endian, ())? as u64;
+        let expected_end_pos = start_pos + expected_data_size;
 
-        // The zone with the greatest number of Launch Stars is
-        // `HellProminenceGalaxy` with a number of 12.
-        let mut inner = Vec::with_capacity(12);
-        let mut zone_id = None;
+        let mut zone: Vec<SpinDriverPathStorageZone> = Vec::new();
 
         loop {
+            let pos = reader.stream_position()?;
+
+            if pos >= expected_end_pos {
+                return Err(binrw::Error::AssertFail {
+                    pos,
+                    message: format!(
+                        "expected a terminator within {expected_data_size} bytes, found none",
+                    ),
+                });
+            }
+
             let value = u8::read(reader)?;
 
             if value == Self::TERM {
@@ -156,31 +192,32 @@ impl BinRead for SpinDriverPathStorageScenario {
             }
 
             if value & Self::NEW_ZONE_ID_MASK == Self::NEW_ZONE_ID_MASK {
-                zone_id = Some((value & Self::ZONE_ID_MASK) as i32);
+                zone.push(SpinDriverPathStorageZone {
+                    zone_id: (value & Self::ZONE_ID_MASK) as i32,
+                    // The zone with the greatest number of Launch Stars is
+                    // `HellProminenceGalaxy` with a number of 12.
+                    one: Vec::with_capacity(12),
+                });
+
                 continue;
             }
 
-            let zone_id = zone_id.expect("invalid byte sequence found");
+            let Some(zone) = zone.last_mut() else {
+                return Err(binrw::Error::AssertFail {
+                    pos,
+                    message: format!("expected a zone before the path state {value:#04X}"),
+                });
+            };
 
-            reader.seek_relative(-(size_of::<u8>() as i64))?;
-            let one = SpinDriverPathStorageOne::read(reader, zone_id)?;
+            reader.seek(SeekFrom::Start(pos))?;
+            let one = SpinDriverPathStorageOne::read(reader)?;
 
-            inner.push(one);
+            zone.one.push(one);
         }
 
-        let end_pos = reader.stream_position()?;
-        let data_size = end_pos - start_pos;
-
-        if data_size != expected_data_size {
-            return Err(binrw::Error::AssertFail {
-                pos: end_pos,
-                message: format!(
-                    "expected to read {expected_data_size} bytes, read {data_size} bytes",
-                ),
-            });
-        }
+        check_data_size(reader, start_pos, expected_data_size)?;
 
-        Ok(Self { one: inner })
+        Ok(Self { zone })
     }
 }
 
@@ -196,20 +233,14 @@ impl BinWrite for SpinDriverPathStorageScenario {
         let start_pos = writer.stream_position()?;
         writer.seek_relative(size_of::<u16>() as i64)?;
 
-        if let Some(max_zone_id) = self.one.iter().map(|o| o.zone_id).max() {
-            for zone_id in 0..=max_zone_id {
-                let mut is_new_zone_id = true;
+        for zone in &self.zone {
+            // The greatest index would be written as the terminator.
+            let zone_id = check_range(writer, "zone index", zone.zone_id, Self::ZONE_ID_MASK - 1)?;
+            let value = zone_id | Self::NEW_ZONE_ID_MASK;
+            value.write(writer)?;
 
-                for one in self.one.iter().filter(|o| o.zone_id == zone_id) {
-                    if is_new_zone_id {
-                        is_new_zone_id = false;
-
-                        let value = zone_id as u8 & Self::ZONE_ID_MASK | Self::NEW_ZONE_ID_MASK;
-                        value.write(writer)?;
-                    }
-
-                    one.write(writer)?;
-                }
+            for one in &zone.one {
+                one.write(writer)?;
             }
         }
 
@@ -226,18 +257,43 @@ impl BinWrite for SpinDriverPathStorageScenario {
     }
 }
 
-/// A container for the state of a Launch Star path.
+/// A container for Launch Star path state in a zone.
 #[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
 #[derive(Debug)]
-pub struct SpinDriverPathStorageOne {
-    /// The index of the associated zone.
+pub struct SpinDriverPathStorageZone {
+    /// The index of the zone.
+    ///
+    /// Writing fails if the index is not within `0..=62`.
     pub zone_id: i32,
 
+    /// The collection of Launch Star path states.
+    pub one: Vec<SpinDriverPathStorageOne>,
+}
+
+/// A container for the state of a Launch Star path.
+///
+/// A completely traced path is stored in a compact form, while an incompletely
+/// traced path is followed by the amount traced.
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+#[derive(Debug)]
+pub struct SpinDriverPathStorageOne {
     /// The Launch Star object's `Obj_arg7` value.
+    ///
+    /// Writing fails if the value is not within `0..=63`.
     pub draw_range_index: i32,
 
-    /// The percentage measuring how much of the path is traced.
-    pub draw_range: f32,
+    /// Determines if the path is completely traced, taking precedence over
+    /// `draw_range_raw` when written.
+    pub is_completed: bool,
+
+    /// The amount of the path traced, in 256ths, if incompletely traced.
+    ///
+    /// A value of `0` is read back as a completely traced path.
+    #[cfg_attr(
+        feature = "serde",
+        serde(default, skip_serializing_if = "Option::is_none")
+    )]
+    pub draw_range_raw: Option<u8>,
 }
 
 impl SpinDriverPathStorageOne {
@@ -253,50 +309,100 @@ impl SpinDriverPathStorageOne {
     /// The scalar for mapping the percentage value to and from the unit interval.
     const DRAW_RANGE_FACTOR: f32 = 256.0;
 
-    /// The minimum percentage value to be considered.
-    const DRAW_RANGE_TOLERANCE: f32 = 0.001;
+    /// Returns the percentage measuring how much of the path is traced.
+    pub fn draw_range(&self) -> f32 {
+        match self.draw_range_raw {
+            _ if self.is_completed => 1.0,
+            Some(0) => 1.0,
+            Some(value) => value as f32 / Self::DRAW_RANGE_FACTOR,
+            None => 0.0,
+        }
+    }
+
+    /// Updates the percentage measuring how much of the path is traced,
+    /// truncated to 256ths.
+    pub fn set_draw_range(&mut self, draw_range: f32) {
+        if draw_range >= 1.0 {
+            self.is_completed = true;
+            self.draw_range_raw = None;
+        } else {
+            let value = (draw_range * Self::DRAW_RANGE_FACTOR) as u8;
+
+            self.is_completed = false;
+            self.draw_range_raw = (value != 0).then_some(value);
+        }
+    }
 
     /// Reads the data from the given reader.
-    fn read<R: Read + Seek>(reader: &mut R, zone_id: i32) -> BinResult<Self> {
+    fn read<R: Read + Seek>(reader: &mut R) -> BinResult<Self> {
         let value = u8::read(reader)?;
 
+        let (is_completed, draw_range_raw) = match value {
+            value if value & Self::FLAG_HI_MASK != 0 => (false, Some(u8::read(reader)?)),
+            value if value & Self::FLAG_LO_MASK != 0 => (true, None),
+            _ => (false, None),
+        };
+
         Ok(Self {
-            zone_id,
             draw_range_index: (value & Self::DRAW_RANGE_INDEX_MASK) as i32,
-            draw_range: match value {
-                value if value & Self::FLAG_HI_MASK != 0 => {
-                    let value = u8::read(reader)?;
-
-                    if value != 0 {
-                        value as f32 / Self::DRAW_RANGE_FACTOR
-                    } else {
-                        1.0
-                    }
-                }
-                value if value & Self::FLAG_LO_MASK != 0 => 1.0,
-                _ => 0.0,
-            },
+            is_completed,
+            draw_range_raw,
         })
     }
 
     /// Writes the data to the given writer.
     fn write<W: Write + Seek>(&self, writer: &mut W) -> BinResult<()> {
-        if self.draw_range < Self::DRAW_RANGE_TOLERANCE {
-            return Ok(());
-        }
-
-        let value = self.draw_range_index as u8 & Self::DRAW_RANGE_INDEX_MASK | Self::FLAG_HI_MASK;
+        let draw_range_raw = self.draw_range_raw.filter(|_| !self.is_completed);
+
+        let flag = match draw_range_raw {
+            _ if self.is_completed => Self::FLAG_LO_MASK,
+            Some(_) => Self::FLAG_HI_MASK,
+            None => 0,
+        };
+
+        let draw_range_index = check_range(
+            writer,
+            "Launch Star path index",
+            self.draw_range_index,
+            Self::DRAW_RANGE_INDEX_MASK,
+        )?;
+        let value = draw_range_index | flag;
         value.write(writer)?;
 
-        let value = (self.draw_range * Self::DRAW_RANGE_FACTOR) as i32;
-
-        if value <= 0 {
-            return Ok(());
+        if let Some(value) = draw_range_raw {
+            value.write(writer)?;
         }
 
-        let value = value as u8;
-        value.write(writer)?;
-
         Ok(())
     }
 }
+
+/// Ensures the value is within `0..=max` to be written in the bits of `max`.
+fn check_range<W: Write + Seek>(writer: &mut W, name: &str, value: i32, max: u8) -> BinResult<u8> {
+    match u8::try_from(value) {
+        Ok(value) if value <= max => Ok(value),
+        _ => Err(binrw::Error::AssertFail {
+            pos: writer.stream_position()?,
+            message: format!("expected a {name} within 0..={max}, found {value}"),
+        }),
+    }
+}
+
+/// Ensures the number of bytes read since `start_pos` equals the serialized size.
+fn check_data_size<R: Read + Seek>(
+    reader: &mut R,
+    start_pos: u64,
+    expected_data_size: u64,
+) -> BinResult<()> {
+    let end_pos = reader.stream_position()?;
+    let data_size = end_pos - start_pos;
+
+    if data_size != expected_data_size {
+        return Err(binrw::Error::AssertFail {
+            pos: end_pos,
+            message: format!("expected to read {expected_data_size} bytes, read {data_size} bytes"),
+        });
+    }
+
+    Ok(())
+}
@@ -0,0 +1,139 @@
+use binrw::io::Cursor;
+
+use super::*;
+
+/// Reads a container from a single galaxy with a single base mission.
+fn read_scenario(scenario: &[u8]) -> BinResult<SpinDriverPathStorage> {
+    let data_size = (6 + scenario.len()) as u16;
+
+    let mut bytes = vec![1, 0x12, 0x34];
+    bytes.extend_from_slice(&data_size.to_be_bytes());
+    bytes.extend_from_slice(&[1, 0]);
+    bytes.extend_from_slice(scenario);
+
+    SpinDriverPathStorage::read_be_args(&mut Cursor::new(&bytes), (bytes.len(),))
+}
+
+/// Returns the position of an assertion failure.
+fn assert_fail_pos<T>(result: BinResult<T>) -> u64 {
+    let Err(err) = result else {
+        panic!("expected an assertion failure, found a value");
+    };
+
+    match err.root_cause() {
+        binrw::Error::AssertFail { pos, .. } => *pos,
+        err => panic!("expected an assertion failure, found {err:?}"),
+    }
+}
+
+#[test]
+fn test_read() {
+    let storage = read_scenario(&[0x00, 0x07, 0xC0, 0x41, 0x82, 0x80, 0xFF]).unwrap();
+    let zone = &storage.get(HashCode::from_raw(0x1234)).unwrap().scenario[0].zone;
+
+    assert_eq!(zone.len(), 1);
+    assert_eq!(zone[0].zone_id, 0);
+
+    let one = &zone[0].one;
+
+    assert_eq!(one.len(), 2);
+    assert_eq!(one[0].draw_range_index, 1);
+    assert_eq!(one[0].draw_range(), 1.0);
+    assert_eq!(one[1].draw_range_index, 2);
+    assert_eq!(one[1].draw_range(), 0.5);
+}
+
+#[test]
+fn test_write_round_trip() {
+    // Unsorted and empty zones, with untraced, completely traced and
+    // incompletely traced paths in both forms.
+    let bytes = [
+        2, 0x12, 0x34, 0x00, 0x12, 1, 0, 0x00, 0x0C, 0xC2, 0x01, 0x41, 0x83, 0x00, 0xC0, 0xC2,
+        0x84, 0x7F, 0xFF, 0x56, 0x78, 0x00, 0x0C, 2, 0, 0x00, 0x03, 0xFF, 0x00, 0x03, 0xFF,
+    ];
+    let storage =
+        SpinDriverPathStorage::read_be_args(&mut Cursor::new(&bytes), (bytes.len(),)).unwrap();
+
+    let mut writer = Cursor::new(Vec::new());
+    storage.write_be(&mut writer).unwrap();
+
+    assert_eq!(writer.into_inner(), bytes);
+}
+
+#[test]
+fn test_set_draw_range() {
+    let mut one = SpinDriverPathStorageOne {
+        draw_range_index: 0,
+        is_completed: false,
+        draw_range_raw: None,
+    };
+
+    one.set_draw_range(0.25);
+    assert_eq!((one.is_completed, one.draw_range_raw), (false, Some(64)));
+    assert_eq!(one.draw_range(), 0.25);
+
+    one.set_draw_range(1.0);
+    assert_eq!((one.is_completed, one.draw_range_raw), (true, None));
+    assert_eq!(one.draw_range(), 1.0);
+
+    one.set_draw_range(0.0);
+    assert_eq!((one.is_completed, one.draw_range_raw), (false, None));
+    assert_eq!(one.draw_range(), 0.0);
+}
+
+#[test]
+fn test_read_path_before_zone() {
+    let result = read_scenario(&[0x00, 0x04, 0x41, 0xFF]);
+
+    assert_eq!(assert_fail_pos(result), 9);
+}
+
+#[test]
+fn test_read_missing_terminator() {
+    let mut scenario = vec![0x00, 0x04, 0xC0];
+    scenario.resize(64, 0x41);
+
+    let result = read_scenario(&scenario);
+
+    assert_eq!(assert_fail_pos(result), 11);
+}
+
+#[test]
+fn test_read_scenario_data_size_mismatch() {
+    let result = read_scenario(&[0x00, 0x08, 0xC0, 0x41, 0xFF, 0xFF]);
+
+    assert_eq!(assert_fail_pos(result), 12);
+}
+
+#[test]
+fn test_read_galaxy_data_size_mismatch() {
+    let bytes = [1, 0x12, 0x34, 0x00, 0x10, 1, 0, 0x00, 0x03, 0xFF];
+    let result = SpinDriverPathStorage::read_be_args(&mut Cursor::new(&bytes), (bytes.len(),));
+
+    assert_eq!(assert_fail_pos(result), 10);
+}
+
+#[test]
+fn test_read_truncated() {
+    let result = read_scenario(&[0x00, 0x07, 0xC0, 0x82]);
+
+    assert!(result.is_err());
+}
+
+#[test]
+fn test_write_out_of_range() {
+    let write = |zone_id, draw_range_index| {
+        let mut storage = read_scenario(&[0x00, 0x05, 0xC0, 0x41, 0xFF]).unwrap();
+        let zone = &mut storage.galaxy[0].scenario[0].zone[0];
+        zone.zone_id = zone_id;
+        zone.one[0].draw_range_index = draw_range_index;
+
+        storage.write_be(&mut Cursor::new(Vec::new()))
+    };
+
+    assert!(write(62, 63).is_ok());
+    assert_eq!(assert_fail_pos(write(63, 0)), 9);
+    assert_eq!(assert_fail_pos(write(-1, 0)), 9);
+    assert_eq!(assert_fail_pos(write(0, 64)), 10);
+    assert_eq!(assert_fail_pos(write(0, -1)), 10);
+}
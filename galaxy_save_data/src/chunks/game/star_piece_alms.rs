@@ -6,20 +6,35 @@ use galaxy_save_core::{bin::Chunk, hash::HashCode};
 #[cfg(feature = "serde")]
 use serde::{Deserialize, Serialize};
 
+use crate::catalog::Dome;
+
+#[cfg(all(test, feature = "serde"))]
+mod tests;
+
 /// A container for Hungry Luma state.
+///
+/// When serialized, each known Hungry Luma of the Comet Observatory is named
+/// after its location.
 #[binrw]
 #[br(import(_data_size: usize))]
 #[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
-#[derive(Debug, Default)]
+#[cfg_attr(
+    feature = "serde",
+    serde(from = "StarPieceAlmsStorageSerde", into = "StarPieceAlmsStorageSerde")
+)]
+#[derive(Debug, Clone, Default)]
 pub struct StarPieceAlmsStorage {
     /// The matrix of Star Bit counters, where each row corresponds to
     /// individual galaxies and the Comet Observatory, respectively, and each
     /// column corresponds to an individual Hungry Luma's consumed number of
     /// Star Bits.
-    star_piece_num: [[u16; 8]; 2],
+    star_piece_num: [[u16; Self::PARTS_NUM]; 2],
 }
 
 impl StarPieceAlmsStorage {
+    /// The number of Star Bit counters in each row.
+    pub const PARTS_NUM: usize = 8;
+
     /// Returns a reference to a Star Bit counter from the row a galaxy.
     pub fn galaxy(&self, index: usize) -> Option<&u16> {
         self.star_piece_num[0].get(index)
@@ -39,6 +54,16 @@ impl StarPieceAlmsStorage {
     pub fn astro_galaxy_mut(&mut self, index: usize) -> Option<&mut u16> {
         self.star_piece_num[1].get_mut(index)
     }
+
+    /// Returns the number of Star Bits consumed by the Hungry Luma.
+    pub fn star_piece_num(&self, luma: HungryLuma) -> u16 {
+        self.star_piece_num[1][luma.index()]
+    }
+
+    /// Returns a mutable reference to the number of Star Bits consumed by the Hungry Luma.
+    pub fn star_piece_num_mut(&mut self, luma: HungryLuma) -> &mut u16 {
+        &mut self.star_piece_num[1][luma.index()]
+    }
 }
 
 impl Chunk for StarPieceAlmsStorage {
@@ -50,3 +75,179 @@ impl Chunk for StarPieceAlmsStorage {
         HashCode::from_raw(hash)
     }
 }
+
+/// A Hungry Luma of the Comet Observatory.
+///
+/// Each Hungry Luma is assigned the index of its Star Bit counter based on its
+/// object's `Obj_arg7` value, as documented for the `TicoGalaxyAlreadyTalk`
+/// event value.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum HungryLuma {
+    /// The Hungry Luma near the Terrace.
+    Terrace,
+
+    /// The Hungry Luma near the Fountain.
+    Fountain,
+
+    /// The Hungry Luma near the Kitchen.
+    Kitchen,
+
+    /// The Hungry Luma near the Gate.
+    Gate,
+
+    /// The Hungry Luma near the Garden.
+    Garden,
+
+    /// The Hungry Luma near the Engine Room.
+    EngineRoom,
+
+    /// The Hungry Luma near the Bedroom.
+    Bedroom,
+}
+
+impl HungryLuma {
+    /// Every known Hungry Luma, in the order of the Star Bit counters.
+    pub const ALL: &[Self] = &[
+        Self::Terrace,
+        Self::Fountain,
+        Self::Kitchen,
+        Self::Gate,
+        Self::Garden,
+        Self::EngineRoom,
+        Self::Bedroom,
+    ];
+
+    /// Returns the Hungry Luma corresponding to the index of a Star Bit
+    /// counter from the Comet Observatory, if known.
+    pub fn from_index(index: usize) -> Option<Self> {
+        Self::ALL.get(index).copied()
+    }
+
+    /// Returns the index of the Star Bit counter from the Comet Observatory.
+    pub const fn index(self) -> usize {
+        self as usize
+    }
+
+    /// Returns the dome near the Hungry Luma, if any.
+    pub const fn dome(self) -> Option<Dome> {
+        match self {
+            Self::Terrace => Some(Dome::Terrace),
+            Self::Fountain => Some(Dome::Fountain),
+            Self::Kitchen => Some(Dome::Kitchen),
+            Self::Gate => None,
+            Self::Garden => Some(Dome::Garden),
+            Self::EngineRoom => Some(Dome::EngineRoom),
+            Self::Bedroom => Some(Dome::Bedroom),
+        }
+    }
+}
+
+/// The serialized form of [`StarPieceAlmsStorage`].
+///
+/// The matrix of Star Bit counters serialized by earlier versions is accepted
+/// when deserializing.
+#[cfg(feature = "serde")]
+#[derive(Serialize, Deserialize)]
+#[serde(untagged)]
+enum StarPieceAlmsStorageSerde {
+    Named {
+        /// The Star Bit counters from the row of galaxies.
+        galaxy: [u16; StarPieceAlmsStorage::PARTS_NUM],
+
+        /// The Star Bit counters from the Comet Observatory.
+        astro_galaxy: AstroGalaxySerde,
+    },
+    Matrix {
+        /// The matrix of Star Bit counters.
+        star_piece_num: [[u16; StarPieceAlmsStorage::PARTS_NUM]; 2],
+    },
+}
+
+/// The serialized form of the Star Bit counters from the Comet Observatory.
+#[cfg(feature = "serde")]
+#[derive(Serialize, Deserialize)]
+struct AstroGalaxySerde {
+    terrace: u16,
+    fountain: u16,
+    kitchen: u16,
+    gate: u16,
+    garden: u16,
+    engine_room: u16,
+    bedroom: u16,
+
+    /// The Star Bit counters not associated with a known Hungry Luma.
+    #[serde(default)]
+    unknown: [u16; StarPieceAlmsStorage::PARTS_NUM - HungryLuma::ALL.len()],
+}
+
+#[cfg(feature = "serde")]
+impl From<StarPieceAlmsStorageSerde> for StarPieceAlmsStorage {
+    fn from(value: StarPieceAlmsStorageSerde) -> Self {
+        let (galaxy, astro_galaxy) = match value {
+            StarPieceAlmsStorageSerde::Named {
+                galaxy,
+                astro_galaxy,
+            } => (galaxy, astro_galaxy),
+            StarPieceAlmsStorageSerde::Matrix { star_piece_num } => {
+                return Self { star_piece_num };
+            }
+        };
+        let AstroGalaxySerde {
+            terrace,
+            fountain,
+            kitchen,
+            gate,
+            garden,
+            engine_room,
+            bedroom,
+            unknown: [unknown],
+        } = astro_galaxy;
+
+        Self {
+            star_piece_num: [
+                galaxy,
+                [
+                    terrace,
+                    fountain,
+                    kitchen,
+                    gate,
+                    garden,
+                    engine_room,
+                    bedroom,
+                    unknown,
+                ],
+            ],
+        }
+    }
+}
+
+#[cfg(feature = "serde")]
+impl From<StarPieceAlmsStorage> for StarPieceAlmsStorageSerde {
+    fn from(value: StarPieceAlmsStorage) -> Self {
+        let [galaxy, astro_galaxy] = value.star_piece_num;
+        let [
+            terrace,
+            fountain,
+            kitchen,
+            gate,
+            garden,
+            engine_room,
+            bedroom,
+            unknown,
+        ] = astro_galaxy;
+
+        Self::Named {
+            galaxy,
+            astro_galaxy: AstroGalaxySerde {
+                terrace,
+                fountain,
+                kitchen,
+                gate,
+                garden,
+                engine_room,
+                bedroom,
+                unknown: [unknown],
+            },
+        }
+    }
+}
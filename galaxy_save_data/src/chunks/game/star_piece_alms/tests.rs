@@ -0,0 +1,40 @@
+use super::*;
+
+#[test]
+fn test_serde_round_trip() {
+    let mut storage = StarPieceAlmsStorage::default();
+    *storage.galaxy_mut(2).unwrap() = 30;
+    *storage.star_piece_num_mut(HungryLuma::Gate) = 400;
+    *storage.astro_galaxy_mut(7).unwrap() = 5;
+
+    let json = serde_json::to_value(&storage).unwrap();
+    assert_eq!(json["galaxy"][2], 30);
+    assert_eq!(json["astro_galaxy"]["gate"], 400);
+    assert_eq!(json["astro_galaxy"]["unknown"][0], 5);
+
+    let storage: StarPieceAlmsStorage = serde_json::from_value(json).unwrap();
+    assert_eq!(storage.galaxy(2), Some(&30));
+    assert_eq!(storage.star_piece_num(HungryLuma::Gate), 400);
+    assert_eq!(storage.astro_galaxy(7), Some(&5));
+}
+
+#[test]
+fn test_serde_matrix() {
+    let json = r#"{"star_piece_num":[[0,0,0,0,0,0,0,0],[1,2,3,4,5,6,7,8]]}"#;
+    let storage: StarPieceAlmsStorage = serde_json::from_str(json).unwrap();
+
+    for luma in HungryLuma::ALL {
+        assert_eq!(storage.star_piece_num(*luma) as usize, luma.index() + 1);
+    }
+    assert_eq!(storage.astro_galaxy(7), Some(&8));
+}
+
+#[test]
+fn test_index() {
+    for (index, luma) in HungryLuma::ALL.iter().enumerate() {
+        assert_eq!(luma.index(), index);
+        assert_eq!(HungryLuma::from_index(index), Some(*luma));
+    }
+    assert_eq!(HungryLuma::from_index(HungryLuma::ALL.len()), None);
+    assert_eq!(HungryLuma::Gate.dome(), None);
+}
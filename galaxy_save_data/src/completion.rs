@@ -0,0 +1,77 @@
+//! Analyses of the collectibles remaining in a user file.
+//!
+//! Hungry Lumas are not listed, as the number of Star Bits each requires is
+//! undocumented. The Stars of the galaxies they transform into are listed
+//! instead.
+
+use crate::{
+    catalog::{Galaxy, MissionKind},
+    chunks::game::GameDataAllGalaxyStorage,
+};
+
+#[cfg(test)]
+mod tests;
+
+/// The collectibles remaining in a user file, in the order of the catalog.
+#[derive(Debug, Clone, Default)]
+pub struct CompletionReport {
+    /// The collection of remaining collectibles.
+    pub missing: Vec<Missing>,
+}
+
+impl CompletionReport {
+    /// Lists every Star and galaxy visit absent from the galaxy states.
+    ///
+    /// A missing galaxy state counts as a galaxy that was never visited.
+    pub fn new(galaxy_storage: Option<&GameDataAllGalaxyStorage>) -> Self {
+        let mut missing = Vec::new();
+
+        for galaxy in Galaxy::ALL.iter().copied() {
+            if galaxy.missions().is_empty() {
+                continue;
+            }
+
+            let storage = galaxy_storage.and_then(|s| s.get(galaxy));
+
+            if storage.is_none_or(|s| s.first_play_flag.count_ones() == 0) {
+                missing.push(Missing::Galaxy(galaxy));
+            }
+
+            for (index, kind) in galaxy.missions().iter().copied().enumerate() {
+                if storage.is_none_or(|s| !s.power_star_flag.test(index as u32)) {
+                    missing.push(Missing::PowerStar {
+                        galaxy,
+                        index,
+                        kind,
+                    });
+                }
+            }
+        }
+
+        Self { missing }
+    }
+
+    /// Returns `true` if no collectibles remain.
+    pub fn is_complete(&self) -> bool {
+        self.missing.is_empty()
+    }
+}
+
+/// A collectible remaining in a user file.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Missing {
+    /// A Star that was not collected.
+    PowerStar {
+        /// The galaxy of the mission.
+        galaxy: Galaxy,
+
+        /// The index of the mission, corresponding to its bit in the Star flags.
+        index: usize,
+
+        /// The kind of the mission.
+        kind: MissionKind,
+    },
+
+    /// A galaxy where no mission was ever selected.
+    Galaxy(Galaxy),
+}
@@ -0,0 +1,52 @@
+use binrw::{BinRead, io::Cursor};
+
+use super::*;
+
+/// Creates an empty container for galaxy state.
+fn empty_storage() -> GameDataAllGalaxyStorage {
+    GameDataAllGalaxyStorage::read_be_args(&mut Cursor::new(&[0; 6]), (6,)).unwrap()
+}
+
+#[test]
+fn test_complete() {
+    let mut galaxy_storage = empty_storage();
+
+    for galaxy in Galaxy::ALL.iter().copied() {
+        let storage = galaxy_storage.insert(galaxy.hash_code());
+
+        for index in 0..galaxy.star_num() {
+            storage.power_star_flag.set(index as u32);
+            storage.first_play_flag.set(index as u32);
+        }
+    }
+
+    let report = CompletionReport::new(Some(&galaxy_storage));
+
+    assert!(report.is_complete(), "{:?}", report.missing);
+}
+
+#[test]
+fn test_empty() {
+    let galaxy_storage = empty_storage();
+
+    for report in [
+        CompletionReport::new(Some(&galaxy_storage)),
+        CompletionReport::new(None),
+    ] {
+        let galaxies = Galaxy::ALL.iter().filter(|g| g.star_num() != 0);
+        let star_num = galaxies.clone().map(|g| g.star_num()).sum::<usize>();
+
+        assert_eq!(report.missing.len(), galaxies.clone().count() + star_num);
+        assert_eq!(
+            report.missing[..2],
+            [
+                Missing::Galaxy(Galaxy::HeavensDoorGalaxy),
+                Missing::PowerStar {
+                    galaxy: Galaxy::HeavensDoorGalaxy,
+                    index: 0,
+                    kind: MissionKind::Normal,
+                },
+            ]
+        );
+    }
+}
@@ -0,0 +1,249 @@
+//! Catalogs of known key-value pairs, mirroring the documentation in `docs/galaxy`.
+
+use std::borrow::Cow;
+
+use galaxy_save_core::hash::{Encoding, HashCode, HashKey};
+
+#[cfg(test)]
+mod tests;
+
+/// Defines an enumeration of known keys alongside their labels, descriptions and default values.
+macro_rules! catalog {
+    (
+        $(#[$meta:meta])*
+        $vis:vis enum $name:ident: $ty:ty {
+            $(
+                #[doc = $doc:literal]
+                $variant:ident = ($label:literal, $default:expr),
+            )*
+        }
+    ) => {
+        $(#[$meta])*
+        #[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+        $vis enum $name {
+            $(
+                #[doc = $doc]
+                $variant,
+            )*
+        }
+
+        impl $name {
+            /// Every known key, in the order of the documentation.
+            pub const ALL: &[Self] = &[$(Self::$variant,)*];
+
+            /// Returns the original label of the key.
+            pub const fn label(self) -> &'static str {
+                match self {
+                    $(Self::$variant => $label,)*
+                }
+            }
+
+            /// Returns the description of the key.
+            pub const fn description(self) -> &'static str {
+                match self {
+                    $(Self::$variant => $doc.trim_ascii(),)*
+                }
+            }
+
+            /// Returns the value assigned to the key by default.
+            pub const fn default_value(self) -> $ty {
+                match self {
+                    $(Self::$variant => $default,)*
+                }
+            }
+
+            /// Returns the hash of the label converted to the character encoding.
+            pub fn hash_code(self, encoding: Encoding) -> HashCode {
+                encoding
+                    .hash(self.label())
+                    .expect("label should be representable in every character encoding")
+            }
+        }
+
+        impl From<$name> for HashKey<'_> {
+            fn from(key: $name) -> Self {
+                Self::Label(Cow::Borrowed(key.label()))
+            }
+        }
+    };
+}
+
+catalog! {
+    /// A known key of a `GameEventFlag`.
+    pub enum Flag: bool {
+        /// Determines if the player was informed about collecting a Bee Mushroom for the first time.
+        BeeMarioFirstTransform = ("ハチマリオ初変身", false),
+        /// Determines if the player was informed about collecting a Boo Mushroom for the first time.
+        TeresaMarioFirstTransform = ("テレサマリオ初変身", false),
+        /// Determines if the player was informed about collecting a Spring Mushroom for the first time.
+        HopperMarioFirstTransform = ("ホッパーマリオ初変身", false),
+        /// Determines if the player was informed about collecting a Fire Flower for the first time.
+        FireMarioFirstTransform = ("ファイアマリオ初変身", false),
+        /// Determines if the player was informed about collecting an Ice Flower for the first time.
+        IceMarioFirstTransform = ("アイスマリオ初変身", false),
+        /// Determines if the player was informed about collecting a Red Star for the first time.
+        FlyingMarioFirstTransform = ("フライングマリオ初変身", false),
+        /// Determines if the player was informed about collecting a Rainbow Star for the first time.
+        InvincibleMarioFirstTransform = ("無敵マリオ初変身", false),
+        /// Determines if the player was informed about collecting a Life Mushroom for the first time.
+        LifeUpMushroomExplanation = ("ライフアップキノコ解説", false),
+        /// Determines if the player was informed about collecting a 1-Up Mushroom for the first time.
+        OneUpMushroomExplanation = ("１ＵＰキノコ解説", false),
+        /// Determines if the player was lectured about ray surfing.
+        SurfingTutorial = ("サーフィンチュートリアル", false),
+        /// Determines if the player was lectured about riding the Rolling Ball.
+        TamakoroTutorial = ("タマコロチュートリアル", false),
+        /// Determines if the Good Egg Galaxy was opened.
+        EggStarGalaxy = ("EggStarGalaxy", false),
+        /// Determines if the Honeyhive Galaxy was opened.
+        HoneyBeeKingdomGalaxy = ("HoneyBeeKingdomGalaxy", false),
+        /// Determines if the Loopdeeloop Galaxy was opened.
+        SurfingLv1Galaxy = ("SurfingLv1Galaxy", false),
+        /// Determines if the Flipswitch Galaxy was opened.
+        FlipPanelExGalaxy = ("FlipPanelExGalaxy", false),
+        /// Determines if the Sweet Sweet Galaxy was opened.
+        BeltConveyerExGalaxy = ("BeltConveyerExGalaxy", false),
+        /// Determines if Bowser Jr.'s Robot Reactor was opened.
+        TriLegLv1Galaxy = ("TriLegLv1Galaxy", false),
+        /// Determines if the Space Junk Galaxy was opened.
+        StarDustGalaxy = ("StarDustGalaxy", false),
+        /// Determines if the Rolling Green Galaxy was opened.
+        TamakoroExLv1Galaxy = ("TamakoroExLv1Galaxy", false),
+        /// Determines if the Battlerock Galaxy was opened.
+        BattleShipGalaxy = ("BattleShipGalaxy", false),
+        /// Determines if the Hurry-Scurry Galaxy was opened.
+        BreakDownPlanetGalaxy = ("BreakDownPlanetGalaxy", false),
+        /// Determines if the Sling Pod Galaxy was opened.
+        CocoonExGalaxy = ("CocoonExGalaxy", false),
+        /// Determines if Bowser's Star Reactor was opened.
+        KoopaBattleVs1Galaxy = ("KoopaBattleVs1Galaxy", false),
+        /// Determines if the Beach Bowl Galaxy was opened.
+        HeavenlyBeachGalaxy = ("HeavenlyBeachGalaxy", false),
+        /// Determines if the Bubble Breeze Galaxy was opened.
+        CubeBubbleExLv1Galaxy = ("CubeBubbleExLv1Galaxy", false),
+        /// Determines if the Ghostly Galaxy was opened.
+        PhantomGalaxy = ("PhantomGalaxy", false),
+        /// Determines if the Buoy Base Galaxy was opened.
+        OceanFloaterLandGalaxy = ("OceanFloaterLandGalaxy", false),
+        /// Determines if the Drip Drop Galaxy was opened.
+        TearDropGalaxy = ("TearDropGalaxy", false),
+        /// Determines if the Boo's Boneyard Galaxy was opened.
+        TeresaMario2DGalaxy = ("TeresaMario2DGalaxy", false),
+        /// Determines if Bowser Jr.'s Airship Armada was opened.
+        KoopaJrShipLv1Galaxy = ("KoopaJrShipLv1Galaxy", false),
+        /// Determines if the Gusty Garden Galaxy was opened.
+        CosmosGardenGalaxy = ("CosmosGardenGalaxy", false),
+        /// Determines if the Freezeflame Galaxy was opened.
+        IceVolcanoGalaxy = ("IceVolcanoGalaxy", false),
+        /// Determines if the Honeyclimb Galaxy was opened.
+        HoneyBeeExGalaxy = ("HoneyBeeExGalaxy", false),
+        /// Determines if the Dusty Dune Galaxy was opened.
+        SandClockGalaxy = ("SandClockGalaxy", false),
+        /// Determines if the Snow Cap Galaxy was opened.
+        SnowCapsuleGalaxy = ("SnowCapsuleGalaxy", false),
+        /// Determines if Bowser's Dark Matter Plant was opened.
+        KoopaBattleVs2Galaxy = ("KoopaBattleVs2Galaxy", false),
+        /// Determines if the Gold Leaf Galaxy was opened.
+        ReverseKingdomGalaxy = ("ReverseKingdomGalaxy", false),
+        /// Determines if the Sea Slide Galaxy was opened.
+        OceanRingGalaxy = ("OceanRingGalaxy", false),
+        /// Determines if the Bonefin Galaxy was opened.
+        SkullSharkGalaxy = ("SkullSharkGalaxy", false),
+        /// Determines if the Toy Time Galaxy was opened.
+        FactoryGalaxy = ("FactoryGalaxy", false),
+        /// Determines if the Sand Spiral Galaxy was opened.
+        TransformationExGalaxy = ("TransformationExGalaxy", false),
+        /// Determines if Bowser Jr.'s Lava Reactor was opened.
+        FloaterOtaKingGalaxy = ("FloaterOtaKingGalaxy", false),
+        /// Determines if the Deep Dark Galaxy was opened.
+        OceanPhantomCaveGalaxy = ("OceanPhantomCaveGalaxy", false),
+        /// Determines if the Dreadnought Galaxy was opened.
+        CannonFleetGalaxy = ("CannonFleetGalaxy", false),
+        /// Determines if the Matter Splatter Galaxy was opened.
+        DarkRoomGalaxy = ("DarkRoomGalaxy", false),
+        /// Determines if the Melty Molten Galaxy was opened.
+        HellProminenceGalaxy = ("HellProminenceGalaxy", false),
+        /// Determines if the Bigmouth Galaxy was opened.
+        FishTunnelGalaxy = ("FishTunnelGalaxy", false),
+        /// Determines if Bowser's Galaxy Reactor was opened.
+        KoopaBattleVs3Galaxy = ("KoopaBattleVs3Galaxy", false),
+        /// Determines if the Rolling Gizmo Galaxy was opened.
+        TamakoroExLv2Galaxy = ("TamakoroExLv2Galaxy", false),
+        /// Determines if the Loopdeeswoop Galaxy was opened.
+        SurfingLv2Galaxy = ("SurfingLv2Galaxy", false),
+        /// Determines if the Bubble Blast Galaxy was opened.
+        CubeBubbleExLv2Galaxy = ("CubeBubbleExLv2Galaxy", false),
+        /// Determines if the Grand Finale Galaxy was opened.
+        PeachCastleFinalGalaxy = ("PeachCastleFinalGalaxy", false),
+        /// Determines if Luigi was talked to since he was rescued from the Ghostly Galaxy.
+        LuigiTalkAfterRescued = ("LuigiTalkAfterRescued", false),
+        /// Determines if Rosalina was talked to in the second mission of the Gateway Galaxy.
+        RosettaTalkAboutTico = ("RosettaTalkAboutTico", false),
+        /// Determines if Rosalina was talked to after the normal ending in the Comet Observatory.
+        RosettaTalkAfterNormalEnding = ("RosettaTalkAfterNormalEnding", false),
+        /// Determines if the 120-Star ending was achieved.
+        ViewCompleteEnding = ("ViewCompleteEnding", false),
+        /// Determines if 9,999 Star Bits were collected.
+        StarPieceCounterStop = ("StarPieceCounterStop", false),
+    }
+}
+
+catalog! {
+    /// A known key of a `GameEventValue`.
+    pub enum Value: u16 {
+        /// Represents the most significant bytes for the best race time in the second mission of the Sea Slide Galaxy, in frames.
+        PenguinRaceOceanRingHi = ("ペンギンレース[オーシャンリング]/hi", 0),
+        /// Represents the least significant bytes for the best race time in the second mission of the Sea Slide Galaxy, in frames.
+        PenguinRaceOceanRingLo = ("ペンギンレース[オーシャンリング]/lo", 5400),
+        /// Represents the most significant bytes for the best race time in the second mission of the Ghostly Galaxy, in frames.
+        TeresaRacePhantomHi = ("テレサレース[ファントム]/hi", 0),
+        /// Represents the least significant bytes for the best race time in the second mission of the Ghostly Galaxy, in frames.
+        TeresaRacePhantomLo = ("テレサレース[ファントム]/lo", 5400),
+        /// Represents the most significant bytes for the best race time in the first mission of the Boo's Boneyard Galaxy, in frames.
+        TeresaRaceDeathPromenadeHi = ("テレサレース[デスプロムナード]/hi", 0),
+        /// Represents the least significant bytes for the best race time in the first mission of the Boo's Boneyard Galaxy, in frames.
+        TeresaRaceDeathPromenadeLo = ("テレサレース[デスプロムナード]/lo", 5400),
+        /// Represents the most significant bytes for the best race time in the first mission of the Loopdeeloop Galaxy, in frames.
+        SurfingTrialHi = ("サーフィン[トライアル]/hi", 0),
+        /// Represents the least significant bytes for the best race time in the first mission of the Loopdeeloop Galaxy, in frames.
+        SurfingTrialLo = ("サーフィン[トライアル]/lo", 5400),
+        /// Represents the most significant bytes for the best race time in the first mission of the Loopdeeswoop Galaxy, in frames.
+        SurfingChallengeHi = ("サーフィン[チャレンジ]/hi", 0),
+        /// Represents the least significant bytes for the best race time in the first mission of the Loopdeeswoop Galaxy, in frames.
+        SurfingChallengeLo = ("サーフィン[チャレンジ]/lo", 5400),
+        /// Represents the remaining number of Stars to collect after completing Bowser's Star Reactor before the Library can open.
+        LibraryOpenNewStarCount = ("LibraryOpenNewStarCount", 1),
+        /// Represents the number of chapters read in Rosalina's Storybook.
+        PictureBookChaptersAlreadyRead = ("絵本既読章", 0),
+        /// Represents a Boolean determining if the disc slot should illuminate when the mailtoad receives a letter.
+        MsgLedPattern = ("MsgLedPattern", 1),
+        /// Represents the state of Luigi's game of hide-and-seek.
+        LuigiEventState = ("LuigiEventState", 0xFF00),
+        /// Represents a bit field determining if an optional Warp Pad was activated.
+        WarpPodSaveBits = ("WarpPodSaveBits", 0b00000000_00000000),
+        /// Represents a bit field determining if a Hungry Luma in the Comet Observatory was talked to.
+        TicoGalaxyAlreadyTalk = ("TicoGalaxyAlreadyTalk", 0b00000000_00000000),
+        /// Represents a bit field determining if a message from an actor was read by the player.
+        MessageAlreadyRead = ("MessageAlreadyRead", 0b00000000_00000000),
+        /// Represents the number of points earned from losing lives and getting game overs which go towards receiving a letter from Princess Peach.
+        MissPointForLetter = ("MissPointForLetter", 0),
+        /// Represents the number of lives lost.
+        MissNum = ("MissNum", 0),
+        /// Represents the most significant bytes for the amount of time spent actively playing the game, in frames.
+        TotalPlayTimeHi = ("TotalPlayTime/hi", 0),
+        /// Represents the least significant bytes for the amount of time spent actively playing the game, in frames.
+        TotalPlayTime = ("TotalPlayTime", 0),
+        /// Represents the state of the Prankster Comet in the Terrace.
+        Comet1Status = ("Comet1Status", 0),
+        /// Represents the state of the Prankster Comet in the Fountain.
+        Comet2Status = ("Comet2Status", 0),
+        /// Represents the state of the Prankster Comet in the Kitchen.
+        Comet3Status = ("Comet3Status", 0),
+        /// Represents the state of the Prankster Comet in the Bedroom.
+        Comet4Status = ("Comet4Status", 0),
+        /// Represents the state of the Prankster Comet in the Engine Room.
+        Comet5Status = ("Comet5Status", 0),
+        /// Represents the state of the Prankster Comet in the Garden.
+        Comet6Status = ("Comet6Status", 0),
+    }
+}
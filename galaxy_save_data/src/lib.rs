@@ -3,5 +3,10 @@
 //! galaxy_save_data is a library for reading and writing `GameData.bin` files
 //! from Super Mario Galaxy.
 
+pub mod catalog;
 pub mod chunks;
+pub mod completion;
+pub mod events;
+pub mod progression;
 pub mod save;
+pub mod summary;
@@ -0,0 +1,262 @@
+//! Consistent progression of a user file through the story.
+
+use galaxy_save_core::{
+    bin::{CapacityError, ChunkHolder},
+    save::Platform,
+};
+use thiserror::Error;
+
+use crate::{catalog::Galaxy, chunks::game::GameDataChunk, events::Flag};
+
+#[cfg(test)]
+mod tests;
+
+/// A named point of sequential progression through the story, mirroring the
+/// table documented on [`GameDataPlayerStatus::story_progress`].
+///
+/// [`GameDataPlayerStatus::story_progress`]: crate::chunks::game::GameDataPlayerStatus::story_progress
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub enum Milestone {
+    /// Immediately after starting the game.
+    GameStart,
+
+    /// After Bowser invades the Star Festival.
+    BowserInvasion,
+
+    /// After Peach's Castle is lifted into space.
+    PeachCastleLifted,
+
+    /// After the sequence with the guiding Luma ends.
+    TicoGuideDemoEnd,
+
+    /// After the spin is granted.
+    SpinGranted,
+
+    /// After Polari provides the first information.
+    ButlerInformationA,
+
+    /// After the lecture on the Star Globe.
+    StarGlobeLecture,
+
+    /// After the lecture on traveling to a galaxy.
+    GalaxyMoveLecture,
+
+    /// After the lecture on Star Bits.
+    StarPieceLecture,
+
+    /// After Bowser Jr.'s Robot Reactor is discovered.
+    KoopaJrRobotPlantFound,
+
+    /// After Bowser's Star Reactor is discovered.
+    KoopaStarPlantFound,
+
+    /// After Bowser Jr.'s Airship Armada is discovered.
+    KoopaJrShipPlantFound,
+
+    /// After Bowser's Dark Matter Plant is discovered.
+    KoopaDarkMatterPlantFound,
+
+    /// After Bowser Jr.'s Lava Reactor is discovered.
+    KoopaJrCreaturePlantFound,
+}
+
+impl Milestone {
+    /// Every milestone, in the order of the story.
+    pub const ALL: &[Self] = &[
+        Self::GameStart,
+        Self::BowserInvasion,
+        Self::PeachCastleLifted,
+        Self::TicoGuideDemoEnd,
+        Self::SpinGranted,
+        Self::ButlerInformationA,
+        Self::StarGlobeLecture,
+        Self::GalaxyMoveLecture,
+        Self::StarPieceLecture,
+        Self::KoopaJrRobotPlantFound,
+        Self::KoopaStarPlantFound,
+        Self::KoopaJrShipPlantFound,
+        Self::KoopaDarkMatterPlantFound,
+        Self::KoopaJrCreaturePlantFound,
+    ];
+
+    /// Returns the original name of the milestone.
+    pub const fn label(self) -> &'static str {
+        match self {
+            Self::GameStart => "ゲーム開始直後",
+            Self::BowserInvasion => "クッパ襲来後",
+            Self::PeachCastleLifted => "ピーチ城浮上後",
+            Self::TicoGuideDemoEnd => "チコガイドデモ終了",
+            Self::SpinGranted => "スピン権利",
+            Self::ButlerInformationA => "バトラー情報Ａ",
+            Self::StarGlobeLecture => "天球儀レクチャー",
+            Self::GalaxyMoveLecture => "ギャラクシー移動レクチャー",
+            Self::StarPieceLecture => "スターピースレクチャー",
+            Self::KoopaJrRobotPlantFound => "クッパＪｒロボプラント発見",
+            Self::KoopaStarPlantFound => "クッパスタープラント発見",
+            Self::KoopaJrShipPlantFound => "クッパＪｒシッププラント発見",
+            Self::KoopaDarkMatterPlantFound => "クッパダークマタープラント発見",
+            Self::KoopaJrCreaturePlantFound => "クッパＪｒクリーチャープラント発見",
+        }
+    }
+
+    /// Returns the value of sequential progression through the story.
+    pub const fn story_progress(self) -> u8 {
+        match self {
+            Self::GameStart => 0,
+            Self::BowserInvasion => 2,
+            Self::PeachCastleLifted => 5,
+            Self::TicoGuideDemoEnd => 10,
+            Self::SpinGranted => 15,
+            Self::ButlerInformationA => 25,
+            Self::StarGlobeLecture => 30,
+            Self::GalaxyMoveLecture => 35,
+            Self::StarPieceLecture => 40,
+            Self::KoopaJrRobotPlantFound => 42,
+            Self::KoopaStarPlantFound => 45,
+            Self::KoopaJrShipPlantFound => 50,
+            Self::KoopaDarkMatterPlantFound => 55,
+            Self::KoopaJrCreaturePlantFound => 60,
+        }
+    }
+
+    /// Returns the latest milestone reached by the value of sequential
+    /// progression through the story.
+    pub fn from_story_progress(story_progress: u8) -> Self {
+        Self::ALL
+            .iter()
+            .copied()
+            .rfind(|m| m.story_progress() <= story_progress)
+            .unwrap_or(Self::GameStart)
+    }
+
+    /// Returns the galaxy revealed by the milestone, if any.
+    ///
+    /// The Good Egg Galaxy is revealed by the lecture on traveling to a
+    /// galaxy, and each reactor by the milestone discovering it.
+    pub const fn galaxy(self) -> Option<Galaxy> {
+        match self {
+            Self::GalaxyMoveLecture => Some(Galaxy::EggStarGalaxy),
+            Self::KoopaJrRobotPlantFound => Some(Galaxy::TriLegLv1Galaxy),
+            Self::KoopaStarPlantFound => Some(Galaxy::KoopaBattleVs1Galaxy),
+            Self::KoopaJrShipPlantFound => Some(Galaxy::KoopaJrShipLv1Galaxy),
+            Self::KoopaDarkMatterPlantFound => Some(Galaxy::KoopaBattleVs2Galaxy),
+            Self::KoopaJrCreaturePlantFound => Some(Galaxy::FloaterOtaKingGalaxy),
+            _ => None,
+        }
+    }
+
+    /// Moves the story to the milestone.
+    ///
+    /// The value of sequential progression is updated, and the event flag of
+    /// each galaxy revealed by a milestone is set if the milestone is reached
+    /// and cleared otherwise. A galaxy state without collected Stars is added
+    /// for each galaxy revealed by a reached milestone if missing. New event
+    /// flags are hashed under the character encoding of the platform. The
+    /// event flags of other galaxies, which are opened by collecting Stars
+    /// rather than by the story, and every existing galaxy state are left as
+    /// is. Nothing is modified if the player, event flag or galaxy state is
+    /// missing, or if the added event flags and galaxy states do not fit
+    /// within the data buffer.
+    pub fn apply(
+        self,
+        chunks: &mut [GameDataChunk],
+        platform: Platform,
+    ) -> Result<(), ProgressError> {
+        if !chunks
+            .iter()
+            .any(|c| matches!(c, GameDataChunk::PlayerStatus(_)))
+        {
+            return Err(MissingChunkError::PlayerStatus.into());
+        }
+
+        let flags = Self::ALL
+            .iter()
+            .filter_map(|m| Some((*m, m.galaxy()?)))
+            .filter_map(|(m, g)| Some((m, *Flag::ALL.iter().find(|f| f.label() == g.label())?)))
+            .collect::<Vec<_>>();
+        let event_flag = chunks
+            .iter()
+            .find_map(|c| match c {
+                GameDataChunk::EventFlag(chunk) => Some(&chunk.inner),
+                _ => None,
+            })
+            .ok_or(MissingChunkError::EventFlag)?;
+        let new_flag_num = flags
+            .iter()
+            .filter(|(m, f)| *m <= self && !event_flag.contains(*f))
+            .count();
+        let galaxy_storage = chunks
+            .iter()
+            .find_map(|c| match c {
+                GameDataChunk::Galaxy(chunk) => Some(&chunk.inner),
+                _ => None,
+            })
+            .ok_or(MissingChunkError::Galaxy)?;
+        let galaxies = Self::ALL
+            .iter()
+            .filter(|m| **m <= self)
+            .filter_map(|m| m.galaxy())
+            .collect::<Vec<_>>();
+        let new_galaxy_num = galaxies
+            .iter()
+            .filter(|g| galaxy_storage.get(**g).is_none())
+            .count();
+        let growth = event_flag.growth(new_flag_num) + galaxy_storage.growth(new_galaxy_num);
+
+        GameDataChunk::check_capacity(chunks, growth)?;
+
+        for chunk in chunks {
+            match chunk {
+                GameDataChunk::PlayerStatus(chunk) => {
+                    chunk.inner.story_progress = self.story_progress();
+                }
+                GameDataChunk::Galaxy(chunk) => {
+                    for galaxy in &galaxies {
+                        chunk.inner.insert(*galaxy);
+                    }
+                }
+                GameDataChunk::EventFlag(chunk) => {
+                    for (milestone, flag) in &flags {
+                        if chunk.inner.contains(*flag) {
+                            chunk.inner.set_flag(*flag, *milestone <= self);
+                        } else if *milestone <= self {
+                            chunk.inner.push(flag.hash_code(platform.encoding()), true);
+                        }
+                    }
+                }
+                _ => {}
+            }
+        }
+
+        Ok(())
+    }
+}
+
+/// An error returned from progressing a user file.
+#[derive(Debug, Error)]
+pub enum ProgressError {
+    /// A required data block is missing.
+    #[error(transparent)]
+    MissingChunk(#[from] MissingChunkError),
+
+    /// The event flags and galaxy states required by the progression do not fit
+    /// within the data buffer.
+    #[error(transparent)]
+    Capacity(#[from] CapacityError),
+}
+
+/// An error returned from progressing a user file without a required data block.
+#[derive(Debug, Error)]
+pub enum MissingChunkError {
+    /// The data block dedicated to preserving player state is missing.
+    #[error("the user file has no player state")]
+    PlayerStatus,
+
+    /// The data block dedicated to preserving event flags is missing.
+    #[error("the user file has no event flag state")]
+    EventFlag,
+
+    /// The data block dedicated to preserving galaxy state is missing.
+    #[error("the user file has no galaxy state")]
+    Galaxy,
+}
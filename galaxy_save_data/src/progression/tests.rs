@@ -0,0 +1,205 @@
+use binrw::{BinRead, io::Cursor};
+use galaxy_save_core::{bin::BinaryDataChunk, hash::HashCode};
+
+use super::*;
+use crate::chunks::game::{GameDataAllGalaxyStorage, GameDataPlayerStatus, GameEventFlagStorage};
+
+/// Creates the data blocks of a user file with every Star of every known galaxy
+/// collected and the event flag of every known galaxy set.
+fn complete_chunks() -> Vec<GameDataChunk> {
+    let mut event_flag = GameEventFlagStorage::read_be_args(&mut Cursor::new(&[]), (0,)).unwrap();
+    let mut galaxy_storage =
+        GameDataAllGalaxyStorage::read_be_args(&mut Cursor::new(&[0; 6]), (6,)).unwrap();
+
+    for galaxy in Galaxy::ALL.iter().copied() {
+        if let Some(flag) = Flag::ALL.iter().find(|f| f.label() == galaxy.label()) {
+            event_flag.push(flag.hash_code(Platform::Wii.encoding()), true);
+        }
+
+        let storage = galaxy_storage.insert(galaxy.hash_code());
+
+        for index in 0..galaxy.star_num() {
+            storage.power_star_flag.set(index as u32);
+        }
+    }
+
+    let player_status = GameDataPlayerStatus {
+        story_progress: Milestone::KoopaJrCreaturePlantFound.story_progress(),
+        ..Default::default()
+    };
+
+    vec![
+        GameDataChunk::PlayerStatus(BinaryDataChunk {
+            inner: player_status,
+        }),
+        GameDataChunk::EventFlag(BinaryDataChunk { inner: event_flag }),
+        GameDataChunk::Galaxy(BinaryDataChunk {
+            inner: galaxy_storage,
+        }),
+    ]
+}
+
+/// Creates the data blocks of a user file at the start of the game.
+fn empty_chunks() -> Vec<GameDataChunk> {
+    let event_flag = GameEventFlagStorage::read_be_args(&mut Cursor::new(&[]), (0,)).unwrap();
+    let galaxy_storage =
+        GameDataAllGalaxyStorage::read_be_args(&mut Cursor::new(&[0; 6]), (6,)).unwrap();
+
+    vec![
+        GameDataChunk::PlayerStatus(BinaryDataChunk {
+            inner: GameDataPlayerStatus::default(),
+        }),
+        GameDataChunk::EventFlag(BinaryDataChunk { inner: event_flag }),
+        GameDataChunk::Galaxy(BinaryDataChunk {
+            inner: galaxy_storage,
+        }),
+    ]
+}
+
+/// Returns the event flag and galaxy states of the data blocks.
+fn storages(chunks: &[GameDataChunk]) -> (&GameEventFlagStorage, &GameDataAllGalaxyStorage) {
+    let event_flag = chunks.iter().find_map(|c| match c {
+        GameDataChunk::EventFlag(chunk) => Some(&chunk.inner),
+        _ => None,
+    });
+    let galaxy_storage = chunks.iter().find_map(|c| match c {
+        GameDataChunk::Galaxy(chunk) => Some(&chunk.inner),
+        _ => None,
+    });
+
+    (event_flag.unwrap(), galaxy_storage.unwrap())
+}
+
+#[test]
+fn test_apply_last_milestone_keeps_galaxy_states() {
+    let mut chunks = complete_chunks();
+    let last = *Milestone::ALL.last().unwrap();
+
+    last.apply(&mut chunks, Platform::Wii).unwrap();
+
+    let (event_flag, galaxy_storage) = storages(&chunks);
+
+    assert_eq!(galaxy_storage.iter().count(), Galaxy::ALL.len());
+
+    for galaxy in Galaxy::ALL.iter().copied() {
+        let storage = galaxy_storage.get(galaxy.hash_code()).unwrap();
+
+        assert_eq!(
+            storage.power_star_flag.count_ones() as usize,
+            galaxy.star_num()
+        );
+
+        if let Some(flag) = Flag::ALL.iter().find(|f| f.label() == galaxy.label()) {
+            assert_eq!(event_flag.get_flag(*flag), Some(true), "{galaxy:?}");
+        }
+    }
+}
+
+#[test]
+fn test_apply_earlier_milestone_clears_later_revealed_galaxies() {
+    let mut chunks = complete_chunks();
+
+    Milestone::KoopaJrRobotPlantFound
+        .apply(&mut chunks, Platform::Wii)
+        .unwrap();
+
+    let (event_flag, galaxy_storage) = storages(&chunks);
+
+    assert_eq!(galaxy_storage.iter().count(), Galaxy::ALL.len());
+    assert_eq!(event_flag.get_flag(Flag::EggStarGalaxy), Some(true));
+    assert_eq!(event_flag.get_flag(Flag::TriLegLv1Galaxy), Some(true));
+    assert_eq!(event_flag.get_flag(Flag::KoopaBattleVs1Galaxy), Some(false));
+    assert_eq!(event_flag.get_flag(Flag::FloaterOtaKingGalaxy), Some(false));
+
+    // Galaxies opened by collecting Stars are left as is.
+    assert_eq!(event_flag.get_flag(Flag::HoneyBeeKingdomGalaxy), Some(true));
+    assert_eq!(event_flag.get_flag(Flag::CosmosGardenGalaxy), Some(true));
+}
+
+#[test]
+fn test_apply_inserts_revealed_galaxies() {
+    let mut chunks = empty_chunks();
+
+    Milestone::KoopaStarPlantFound
+        .apply(&mut chunks, Platform::Wii)
+        .unwrap();
+
+    let (event_flag, galaxy_storage) = storages(&chunks);
+    let galaxies = [
+        Galaxy::EggStarGalaxy,
+        Galaxy::TriLegLv1Galaxy,
+        Galaxy::KoopaBattleVs1Galaxy,
+    ];
+
+    assert_eq!(galaxy_storage.iter().count(), galaxies.len());
+
+    for galaxy in galaxies {
+        let storage = galaxy_storage.get(galaxy).unwrap();
+
+        assert_eq!(storage.power_star_flag.count_ones(), 0, "{galaxy:?}");
+        assert_eq!(event_flag.get(galaxy.label()), Some(true));
+    }
+
+    assert!(galaxy_storage.get(Galaxy::KoopaJrShipLv1Galaxy).is_none());
+
+    // Moving backwards keeps the added galaxy states.
+    Milestone::GameStart
+        .apply(&mut chunks, Platform::Wii)
+        .unwrap();
+
+    let (event_flag, galaxy_storage) = storages(&chunks);
+
+    assert_eq!(galaxy_storage.iter().count(), galaxies.len());
+    assert_eq!(event_flag.get_flag(Flag::EggStarGalaxy), Some(false));
+}
+
+#[test]
+fn test_apply_missing_chunk() {
+    let mut chunks = complete_chunks();
+
+    chunks.retain(|c| !matches!(c, GameDataChunk::EventFlag(_)));
+
+    assert!(matches!(
+        Milestone::GameStart.apply(&mut chunks, Platform::Wii),
+        Err(ProgressError::MissingChunk(MissingChunkError::EventFlag))
+    ));
+
+    let mut chunks = complete_chunks();
+
+    chunks.retain(|c| !matches!(c, GameDataChunk::Galaxy(_)));
+
+    assert!(matches!(
+        Milestone::GameStart.apply(&mut chunks, Platform::Wii),
+        Err(ProgressError::MissingChunk(MissingChunkError::Galaxy))
+    ));
+}
+
+#[test]
+fn test_apply_exceeds_capacity() {
+    let mut chunks = empty_chunks();
+    let mut raw = 0;
+
+    // Fills the data buffer with an even number of placeholder keys, so that
+    // no new key fits within the alignment padding.
+    while GameDataChunk::remaining_capacity(&chunks).unwrap() > 0 || raw % 2 != 0 {
+        if let GameDataChunk::EventFlag(chunk) = &mut chunks[1] {
+            chunk.inner.push(HashCode::from_raw(raw), false);
+        }
+
+        raw += 1;
+    }
+
+    assert!(matches!(
+        Milestone::GalaxyMoveLecture.apply(&mut chunks, Platform::Wii),
+        Err(ProgressError::Capacity(_))
+    ));
+    assert!(matches!(
+        &chunks[0],
+        GameDataChunk::PlayerStatus(chunk) if chunk.inner.story_progress == 0
+    ));
+    assert!(matches!(
+        &chunks[1],
+        GameDataChunk::EventFlag(chunk) if !chunk.inner.contains(Flag::EggStarGalaxy)
+    ));
+    assert_eq!(storages(&chunks).1.iter().count(), 0);
+}
@@ -0,0 +1,86 @@
+//! Summaries of the collection progress of a user file.
+
+use galaxy_save_core::hash::HashCode16;
+
+use crate::{
+    catalog::{Dome, Galaxy},
+    chunks::game::{GameDataAllGalaxyStorage, galaxy::GameDataSomeGalaxyStorage},
+};
+
+#[cfg(test)]
+mod tests;
+
+/// A summary of the collection progress of a user file.
+#[derive(Debug, Clone, Default)]
+pub struct GameDataSummary {
+    /// The total number of collected Stars.
+    pub power_star_num: u32,
+
+    /// The summary of each stored galaxy state.
+    pub galaxy: Vec<GalaxySummary>,
+}
+
+impl GameDataSummary {
+    /// Summarizes the galaxy states.
+    pub fn new(storage: &GameDataAllGalaxyStorage) -> Self {
+        let galaxy = storage.iter().map(GalaxySummary::new).collect::<Vec<_>>();
+        let power_star_num = galaxy.iter().map(|g| g.power_star_num).sum();
+
+        Self {
+            power_star_num,
+            galaxy,
+        }
+    }
+
+    /// Returns the summary of the galaxy, if stored.
+    pub fn get(&self, galaxy: Galaxy) -> Option<&GalaxySummary> {
+        self.galaxy.iter().find(|g| g.galaxy == Some(galaxy))
+    }
+
+    /// Returns the number of collected Stars in the galaxies of the dome.
+    pub fn dome_power_star_num(&self, dome: Dome) -> u32 {
+        self.galaxy
+            .iter()
+            .filter(|g| g.galaxy.and_then(Galaxy::dome) == Some(dome))
+            .map(|g| g.power_star_num)
+            .sum()
+    }
+}
+
+/// A summary of the collection progress of a galaxy.
+#[derive(Debug, Clone)]
+pub struct GalaxySummary {
+    /// The hashed internal name of the galaxy, truncated to the least significant 16 bits.
+    pub galaxy_name: HashCode16,
+
+    /// The catalog entry of the galaxy, if known.
+    pub galaxy: Option<Galaxy>,
+
+    /// The number of collected Stars.
+    pub power_star_num: u32,
+
+    /// The greatest number of collected coins for each mission.
+    pub max_coin_num: [u16; u8::BITS as usize],
+}
+
+impl GalaxySummary {
+    /// Summarizes the galaxy state.
+    pub fn new(storage: &GameDataSomeGalaxyStorage) -> Self {
+        Self {
+            galaxy_name: storage.galaxy_name(),
+            galaxy: storage.galaxy(),
+            power_star_num: storage.power_star_flag.count_ones(),
+            max_coin_num: storage.max_coin_num,
+        }
+    }
+
+    /// Returns the number of Stars that can be collected, if the galaxy is known.
+    pub fn power_star_max(&self) -> Option<usize> {
+        self.galaxy.map(Galaxy::star_num)
+    }
+
+    /// Returns the greatest number of collected coins across every mission.
+    pub fn best_coin_num(&self) -> u16 {
+        self.max_coin_num.iter().copied().max().unwrap_or_default()
+    }
+}
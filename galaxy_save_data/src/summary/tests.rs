@@ -0,0 +1,135 @@
+use binrw::{BinRead, io::Cursor};
+use galaxy_save_core::hash::HashCode;
+
+use super::*;
+
+/// Creates an empty container for galaxy state.
+fn empty_storage() -> GameDataAllGalaxyStorage {
+    GameDataAllGalaxyStorage::read_be_args(&mut Cursor::new(&[0; 6]), (6,)).unwrap()
+}
+
+#[test]
+fn test_galaxy_tallies() {
+    let mut storage = empty_storage();
+
+    let galaxy = storage.insert(Galaxy::EggStarGalaxy);
+    galaxy.power_star_flag.set(0);
+    galaxy.power_star_flag.set(3);
+    galaxy.max_coin_num[0] = 50;
+    galaxy.max_coin_num[3] = 120;
+
+    storage.insert(Galaxy::HoneyBeeKingdomGalaxy);
+
+    let summary = GameDataSummary::new(&storage);
+
+    assert_eq!(summary.power_star_num, 2);
+    assert_eq!(summary.galaxy.len(), 2);
+
+    let galaxy = summary.get(Galaxy::EggStarGalaxy).unwrap();
+
+    assert_eq!(
+        galaxy.galaxy_name,
+        HashCode16::from(Galaxy::EggStarGalaxy.hash_code())
+    );
+    assert_eq!(galaxy.power_star_num, 2);
+    assert_eq!(galaxy.power_star_max(), Some(6));
+    assert_eq!(galaxy.best_coin_num(), 120);
+
+    let galaxy = summary.get(Galaxy::HoneyBeeKingdomGalaxy).unwrap();
+
+    assert_eq!(galaxy.power_star_num, 0);
+    assert_eq!(galaxy.best_coin_num(), 0);
+    assert!(summary.get(Galaxy::StarDustGalaxy).is_none());
+}
+
+#[test]
+fn test_dome_tallies() {
+    let mut storage = empty_storage();
+
+    storage.insert(Galaxy::EggStarGalaxy).power_star_flag.set(0);
+    storage
+        .insert(Galaxy::SurfingLv1Galaxy)
+        .power_star_flag
+        .set(0);
+    storage
+        .insert(Galaxy::StarDustGalaxy)
+        .power_star_flag
+        .set(1);
+    storage
+        .insert(Galaxy::HeavensDoorGalaxy)
+        .power_star_flag
+        .set(0);
+
+    let summary = GameDataSummary::new(&storage);
+
+    assert_eq!(summary.power_star_num, 4);
+    assert_eq!(summary.dome_power_star_num(Dome::Terrace), 2);
+    assert_eq!(summary.dome_power_star_num(Dome::Fountain), 1);
+    assert_eq!(summary.dome_power_star_num(Dome::Kitchen), 0);
+    assert_eq!(
+        Dome::ALL
+            .iter()
+            .map(|d| summary.dome_power_star_num(*d))
+            .sum::<u32>(),
+        3
+    );
+}
+
+#[test]
+fn test_grand_stars() {
+    let mut storage = empty_storage();
+
+    // The Grand Stars are stored as the only Star of each boss galaxy.
+    for galaxy in [
+        Galaxy::TriLegLv1Galaxy,
+        Galaxy::KoopaBattleVs1Galaxy,
+        Galaxy::KoopaBattleVs3Galaxy,
+    ] {
+        storage.insert(galaxy).power_star_flag.set(0);
+    }
+
+    let summary = GameDataSummary::new(&storage);
+    let galaxy = summary.get(Galaxy::KoopaBattleVs3Galaxy).unwrap();
+
+    assert_eq!(summary.power_star_num, 3);
+    assert_eq!(galaxy.power_star_num, 1);
+    assert_eq!(galaxy.power_star_max(), Some(1));
+    assert_eq!(summary.dome_power_star_num(Dome::Terrace), 1);
+    assert_eq!(summary.dome_power_star_num(Dome::Fountain), 1);
+    assert_eq!(summary.dome_power_star_num(Dome::Garden), 1);
+}
+
+#[test]
+fn test_unknown_galaxy() {
+    let mut storage = empty_storage();
+    storage
+        .insert(HashCode::from("UnknownGalaxy"))
+        .power_star_flag
+        .set(0);
+
+    let summary = GameDataSummary::new(&storage);
+    let galaxy = &summary.galaxy[0];
+
+    assert_eq!(summary.power_star_num, 1);
+    assert!(galaxy.galaxy.is_none());
+    assert!(galaxy.power_star_max().is_none());
+    assert!(
+        Dome::ALL
+            .iter()
+            .all(|d| summary.dome_power_star_num(*d) == 0)
+    );
+}
+
+#[test]
+fn test_empty() {
+    let summary = GameDataSummary::new(&empty_storage());
+
+    assert_eq!(summary.power_star_num, 0);
+    assert!(summary.galaxy.is_empty());
+    assert!(summary.get(Galaxy::EggStarGalaxy).is_none());
+    assert!(
+        Dome::ALL
+            .iter()
+            .all(|d| summary.dome_power_star_num(*d) == 0)
+    );
+}
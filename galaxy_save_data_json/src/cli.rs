@@ -1,4 +1,8 @@
-use clap::{Parser, ValueEnum};
+use clap::{
+    Parser,
+    builder::{PossibleValue, PossibleValuesParser, TypedValueParser},
+};
+use galaxy_save_core::save::Platform;
 
 /// Convert Super Mario Galaxy save files to and from JSON.
 #[derive(Parser)]
@@ -18,44 +22,37 @@ pub struct Args {
     #[arg(short, long, requires("labels"))]
     pub strict: bool,
 
+    /// Include the English translation of annotated hash labels in JSON files.
+    #[arg(short, long, requires("labels"))]
+    pub translate: bool,
+
     /// The source or target console.
-    #[arg(short, long, default_value_t, value_enum)]
+    #[arg(short, long, default_value = "wii", value_parser = platform_parser())]
     pub platform: Platform,
 
-    /// Parse the save file even if the header is invalid.
+    /// The templates and dictionaries file path for recovering the labels of unknown hashes.
     #[arg(short, long)]
-    pub force: bool,
-}
-
-/// A compatible console.
-#[derive(Debug, Clone, Copy, Default, ValueEnum)]
-pub enum Platform {
-    /// Nintendo Wii
-    #[default]
-    Wii,
-
-    /// NVIDIA Shield TV
-    ShieldTv,
+    pub recover: Option<String>,
 
-    /// Nintendo Switch (Super Mario 3D All-Stars)
-    Switch,
-}
-
-/// A character encoding for labels prior to hashing.
-#[derive(Debug, Clone, Copy, PartialEq)]
-pub enum LabelEncoding {
-    /// Shift JIS
-    ShiftJis,
+    /// Print the collectibles remaining in each user file instead of converting.
+    #[arg(short, long)]
+    pub completion: bool,
 
-    /// UTF-8
-    Utf8,
+    /// Parse the save file even if the header is invalid.
+    #[arg(short, long)]
+    pub force: bool,
 }
 
-impl From<Platform> for LabelEncoding {
-    fn from(platform: Platform) -> Self {
-        match platform {
-            Platform::Wii | Platform::ShieldTv => Self::ShiftJis,
-            Platform::Switch => Self::Utf8,
-        }
-    }
+/// Returns the parser for the compatible consoles.
+fn platform_parser() -> impl TypedValueParser<Value = Platform> {
+    PossibleValuesParser::new([
+        PossibleValue::new("wii").help("Nintendo Wii"),
+        PossibleValue::new("shield-tv").help("NVIDIA Shield TV"),
+        PossibleValue::new("switch").help("Nintendo Switch (Super Mario 3D All-Stars)"),
+    ])
+    .map(|value| match value.as_str() {
+        "shield-tv" => Platform::ShieldTv,
+        "switch" => Platform::Switch,
+        _ => Platform::Wii,
+    })
 }
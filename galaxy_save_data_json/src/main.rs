@@ -4,24 +4,31 @@ use std::{
 };
 
 use clap::Parser;
-use galaxy_save_core::hash::{HashCodeMap, ParseLabelError};
-use galaxy_save_data::save::SaveDataFile;
+use galaxy_save_core::{
+    event::GameEventFlag,
+    hash::{HashCode, HashCodeMap, LabelRecovery, ParseLabelError},
+    save::Platform,
+};
+use galaxy_save_data::{
+    chunks::game::{GameDataChunk, GameDataHolder},
+    completion::Missing,
+    save::{SaveDataFile, SaveDataUserFile},
+};
 
 mod cli;
 
-use cli::{Args, LabelEncoding, Platform};
+use cli::Args;
 
-fn read_data_write_json<P: AsRef<Path> + ToString>(
+fn read_data<P: AsRef<Path>>(
     input_path: P,
-    output_path: Option<String>,
     platform: Platform,
     check: bool,
-) {
+) -> Option<SaveDataFile> {
     let result = match platform {
         Platform::Wii | Platform::ShieldTv => {
             if check && let Err(error) = SaveDataFile::check_be_file(&input_path) {
                 eprintln!("Failed to validate save file: {error}");
-                return;
+                return None;
             }
 
             SaveDataFile::read_be_file(&input_path)
@@ -29,25 +36,33 @@ fn read_data_write_json<P: AsRef<Path> + ToString>(
         Platform::Switch => {
             if check && let Err(error) = SaveDataFile::check_le_file(&input_path) {
                 eprintln!("Failed to validate save file: {error}");
-                return;
+                return None;
             }
 
             SaveDataFile::read_le_file(&input_path)
         }
     };
 
-    match result {
-        Ok(save_data) => {
-            let output_path = output_path
-                .map(PathBuf::from)
-                .unwrap_or_else(|| input_path.as_ref().with_added_extension("json"));
-            let json = serde_json::to_string_pretty(&save_data).unwrap();
+    result.inspect_err(|error| eprintln!("{error}")).ok()
+}
 
-            if let Err(error) = fs::write(output_path, json) {
-                eprintln!("Failed to write JSON file: {error}");
-            }
-        }
-        Err(error) => eprintln!("{error}"),
+fn read_data_write_json<P: AsRef<Path> + ToString>(
+    input_path: P,
+    output_path: Option<String>,
+    platform: Platform,
+    check: bool,
+) {
+    let Some(save_data) = read_data(&input_path, platform, check) else {
+        return;
+    };
+
+    let output_path = output_path
+        .map(PathBuf::from)
+        .unwrap_or_else(|| input_path.as_ref().with_added_extension("json"));
+    let json = serde_json::to_string_pretty(&save_data).unwrap();
+
+    if let Err(error) = fs::write(output_path, json) {
+        eprintln!("Failed to write JSON file: {error}");
     }
 }
 
@@ -76,21 +91,134 @@ fn read_json_write_data<P: AsRef<Path>>(
     }
 }
 
+fn recover_labels<P: AsRef<Path>>(
+    input_path: P,
+    recovery_path: String,
+    platform: Platform,
+    check: bool,
+) {
+    let Some(save_data) = read_data(&input_path, platform, check) else {
+        return;
+    };
+
+    let encoding = platform.encoding();
+    let recovery = match LabelRecovery::read(recovery_path, encoding) {
+        Ok(recovery) => recovery,
+        Err(error) => {
+            eprintln!("Failed to read recovery file: {error}");
+            return;
+        }
+    };
+
+    let mut targets = Vec::new();
+
+    for user_file_info in &save_data.user_file_info {
+        let SaveDataUserFile::GameData(game_data) = &*user_file_info.user_file else {
+            continue;
+        };
+
+        for chunk in &game_data.chunks {
+            match chunk {
+                GameDataChunk::EventFlag(storage) => {
+                    targets.extend(storage.inner.iter().map(|f| {
+                        let hash = HashCode::from_raw(f.key().into());
+
+                        (hash, Some(GameEventFlag::KEY_WIDTH))
+                    }))
+                }
+                GameDataChunk::EventValue(storage) => targets.extend(
+                    storage
+                        .inner
+                        .iter()
+                        .map(|v| (HashCode::from(v.key()), Some(u16::BITS))),
+                ),
+                _ => {}
+            }
+        }
+    }
+
+    let label_map_binding = HashCodeMap::current();
+    let label_map = label_map_binding.lock();
+
+    targets.retain(|(hash, width)| label_map.label_of(*hash, *width).is_none());
+    drop(label_map);
+
+    match recovery.recover_all(targets) {
+        Ok(recovered) => {
+            for recovered_label in recovered {
+                println!(
+                    "{:#X} ({}-bit): {}",
+                    recovered_label.hash.into_raw(),
+                    recovered_label.width.unwrap_or(u32::BITS),
+                    recovered_label.label
+                );
+            }
+        }
+        Err(error) => eprintln!("Failed to recover labels: {error}"),
+    }
+}
+
+fn describe_missing(missing: Missing) -> String {
+    match missing {
+        Missing::PowerStar {
+            galaxy,
+            index,
+            kind,
+        } => format!(
+            "Star {} ({}) in the {}",
+            index + 1,
+            kind.name(),
+            galaxy.name()
+        ),
+        Missing::Galaxy(galaxy) => format!("First visit to the {}", galaxy.name()),
+    }
+}
+
+fn report_completion<P: AsRef<Path>>(input_path: P, platform: Platform, check: bool) {
+    let Some(save_data) = read_data(&input_path, platform, check) else {
+        return;
+    };
+
+    for user_file_info in &save_data.user_file_info {
+        let SaveDataUserFile::GameData(game_data) = &*user_file_info.user_file else {
+            continue;
+        };
+
+        let name = user_file_info.name.to_str().unwrap_or_default();
+        let summary = game_data.summary();
+        let completion = game_data.completion();
+
+        println!("{name}: {} Stars", summary.power_star_num);
+
+        for missing in completion.missing {
+            println!("  Missing: {}", describe_missing(missing));
+        }
+    }
+}
+
 fn try_read_labels(
     labels_path: Option<String>,
     platform: Platform,
     strict: bool,
+    translate: bool,
 ) -> Result<(), ParseLabelError> {
     let labels_path = PathBuf::from(labels_path.unwrap_or("labels.txt".into()));
     let label_map_binding = HashCodeMap::get();
     let mut label_map = label_map_binding.lock();
 
-    match LabelEncoding::from(platform) {
-        LabelEncoding::ShiftJis => label_map.read_shift_jis(labels_path)?,
-        LabelEncoding::Utf8 => label_map.read_utf8(labels_path)?,
-    }
-
+    label_map.set_encoding(Some(platform.encoding()));
+    label_map.read(labels_path)?;
     label_map.set_strict(strict);
+    label_map.set_translated(translate);
+
+    for collision in label_map.collisions() {
+        eprintln!(
+            "Labels share the same {}-bit hash {:#X}: {}",
+            collision.width,
+            collision.hash.into_raw(),
+            collision.labels.join(", ")
+        );
+    }
 
     Ok(())
 }
@@ -98,10 +226,20 @@ fn try_read_labels(
 fn main() {
     let args = Args::parse();
 
-    if let Err(error) = try_read_labels(args.labels, args.platform, args.strict) {
+    if let Err(error) = try_read_labels(args.labels, args.platform, args.strict, args.translate) {
         eprintln!("Failed to read labels file: {error}");
     }
 
+    if let Some(recovery_path) = args.recover {
+        recover_labels(args.input, recovery_path, args.platform, !args.force);
+        return;
+    }
+
+    if args.completion {
+        report_completion(args.input, args.platform, !args.force);
+        return;
+    }
+
     match Path::new(&args.input)
         .extension()
         .expect("input file path should contain an extension")